@@ -51,6 +51,8 @@ pub struct State {
 pub struct History {
     revisions: Vec<Revision>,
     current: usize,
+    /// The revision [`History::edit_positions`] was last computed for, along with the result.
+    edit_positions: Option<(usize, Vec<usize>)>,
 }
 
 /// A single point in history. See [History] for more information.
@@ -77,6 +79,7 @@ fn default() -> Self {
                 timestamp: Instant::now(),
             }],
             current: 0,
+            edit_positions: None,
         }
     }
 }
@@ -114,6 +117,12 @@ pub fn current_revision(&self) -> usize {
         self.current
     }
 
+    /// The index of the most recently committed revision.
+    #[inline]
+    pub fn latest_revision(&self) -> usize {
+        self.revisions.len() - 1
+    }
+
     #[inline]
     pub const fn at_root(&self) -> bool {
         self.current == 0
@@ -159,25 +168,69 @@ pub fn last_edit_pos(&self) -> Option<usize> {
         if self.current == 0 {
             return None;
         }
-        let current_revision = &self.revisions[self.current];
-        let primary_selection = current_revision
+        Some(self.edit_pos(self.current))
+    }
+
+    /// Get the positions of all changes on the path from the root to the current
+    /// revision, mapped through the later changes so that they point into the current
+    /// document. Positions are ordered from the oldest change to the most recent one and
+    /// consecutive duplicates are removed.
+    ///
+    /// The result is cached per revision, so that it is only extended when a new revision
+    /// is committed on top of the cached one.
+    pub fn edit_positions(&mut self) -> &[usize] {
+        let positions = match self.edit_positions.take() {
+            Some((revision, positions)) if revision == self.current => positions,
+            Some((revision, mut positions))
+                if self.current != 0 && self.revisions[self.current].parent == revision =>
+            {
+                self.push_edit_pos(&mut positions, self.current);
+                positions.dedup();
+                positions
+            }
+            _ => {
+                let mut path = self.path_up(self.current, 0);
+                path.reverse();
+
+                let mut positions = Vec::with_capacity(path.len());
+                for revision in path {
+                    self.push_edit_pos(&mut positions, revision);
+                }
+                positions.dedup();
+                positions
+            }
+        };
+        &self.edit_positions.insert((self.current, positions)).1
+    }
+
+    /// Map `positions` through the changes of `revision` and append the position of the
+    /// revision's own change.
+    fn push_edit_pos(&self, positions: &mut Vec<usize>, revision: usize) {
+        self.revisions[revision]
+            .transaction
+            .changes()
+            .update_positions(positions.iter_mut().map(|pos| (pos, Assoc::After)));
+        positions.push(self.edit_pos(revision));
+    }
+
+    /// Position of the change made by the given (non-root) revision, in the document as it
+    /// was right after that revision.
+    fn edit_pos(&self, revision: usize) -> usize {
+        let revision = &self.revisions[revision];
+        let primary_selection = revision
             .inversion
             .selection()
             .expect("inversion always contains a selection")
             .primary();
-        let (_from, to, _fragment) = current_revision
+        let (_from, to, _fragment) = revision
             .transaction
             .changes_iter()
             // find a change that matches the primary selection
             .find(|(from, to, _fragment)| Range::new(*from, *to).overlaps(&primary_selection))
             // or use the first change
-            .or_else(|| current_revision.transaction.changes_iter().next())
+            .or_else(|| revision.transaction.changes_iter().next())
             .unwrap();
-        let pos = current_revision
-            .transaction
-            .changes()
-            .map_pos(to, Assoc::After);
-        Some(pos)
+        revision.transaction.changes().map_pos(to, Assoc::After)
     }
 
     fn lowest_common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
@@ -441,6 +494,41 @@ fn redo(history: &mut History, state: &mut State) {
         assert_eq!("hello", state.doc);
     }
 
+    #[test]
+    fn test_edit_positions() {
+        let mut history = History::default();
+        let mut state = State {
+            doc: Rope::from("hello"),
+            selection: Selection::point(0),
+        };
+        assert!(history.edit_positions().is_empty());
+
+        let transaction1 =
+            Transaction::change(&state.doc, vec![(5, 5, Some(" world!".into()))].into_iter());
+        history.commit_revision(&transaction1, &state);
+        transaction1.apply(&mut state.doc);
+        assert_eq!(history.edit_positions(), [12]);
+
+        let transaction2 =
+            Transaction::change(&state.doc, vec![(0, 0, Some("> ".into()))].into_iter());
+        history.commit_revision(&transaction2, &state);
+        transaction2.apply(&mut state.doc);
+        assert_eq!("> hello world!", state.doc);
+        // the older position is shifted by the later insertion
+        assert_eq!(history.edit_positions(), [14, 2]);
+
+        history.undo();
+        assert_eq!(history.edit_positions(), [12]);
+        history.redo();
+        assert_eq!(history.edit_positions(), [14, 2]);
+
+        // changing the same place again keeps both entries
+        let transaction3 = Transaction::change(&state.doc, vec![(13, 14, None)].into_iter());
+        history.commit_revision(&transaction3, &state);
+        transaction3.apply(&mut state.doc);
+        assert_eq!(history.edit_positions(), [13, 2, 13]);
+    }
+
     #[test]
     fn test_earlier_later() {
         let mut history = History::default();
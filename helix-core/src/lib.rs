@@ -21,6 +21,7 @@
 pub mod match_brackets;
 pub mod movement;
 pub mod object;
+pub mod patch;
 mod position;
 pub mod search;
 pub mod selection;
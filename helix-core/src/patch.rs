@@ -0,0 +1,347 @@
+//! Parsing and applying unified diffs, as produced by `diff -u` or `git diff`.
+
+use std::fmt::Display;
+
+use crate::{Rope, Tendril, Transaction};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The patch does not contain any hunk.
+    Empty,
+    /// The patch line with the given (1-based) number could not be parsed.
+    Malformed(usize),
+    /// The hunk with the given (1-based) index does not match the text it is applied to.
+    HunkMismatch(usize),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Error::Empty => f.write_str("Patch does not contain any changes"),
+            Error::Malformed(line) => write!(f, "Malformed patch at line {line}"),
+            Error::HunkMismatch(hunk) => write!(f, "Hunk {hunk} does not apply"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// The changes a patch makes to a single file.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FilePatch {
+    /// Path of the original file as written in the `---` header, without the `a/` prefix used
+    /// by git. This is `/dev/null` for created files.
+    pub old_path: String,
+    /// Path of the file as written in the `+++` header, without the `b/` prefix used by git.
+    /// This is `/dev/null` for deleted files.
+    pub path: String,
+    pub hunks: Vec<Hunk>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hunk {
+    /// The (0-based) line of the original text at which `old` starts.
+    pub old_start: usize,
+    /// Context and removed lines, including their line endings.
+    pub old: Vec<String>,
+    /// Context and added lines, including their line endings.
+    pub new: Vec<String>,
+}
+
+#[derive(Clone, Copy)]
+enum Side {
+    Old,
+    New,
+    Both,
+}
+
+/// Parses a unified diff. Lines outside of file headers and hunks, like `diff --git` and
+/// `index` lines or a commit message, are ignored.
+pub fn parse(patch: &str) -> Result<Vec<FilePatch>> {
+    let mut files: Vec<FilePatch> = Vec::new();
+    let mut old_path = None;
+    let mut lines = patch.split_inclusive('\n').enumerate().peekable();
+
+    while let Some((i, line)) = lines.next() {
+        if let Some(path) = line.strip_prefix("--- ") {
+            old_path = Some(parse_path(path, "a/"));
+            continue;
+        }
+        if let Some(path) = line.strip_prefix("+++ ") {
+            let path = parse_path(path, "b/");
+            files.push(FilePatch {
+                old_path: old_path.take().unwrap_or_else(|| path.clone()),
+                path,
+                hunks: Vec::new(),
+            });
+            continue;
+        }
+        let Some(header) = line.strip_prefix("@@ ") else {
+            continue;
+        };
+        let file = files.last_mut().ok_or(Error::Malformed(i + 1))?;
+        let (old_start, mut old_left, mut new_left) =
+            parse_hunk_header(header).ok_or(Error::Malformed(i + 1))?;
+        let mut hunk = Hunk {
+            // An empty old range names the line after which the new lines are inserted.
+            old_start: if old_left == 0 {
+                old_start
+            } else {
+                old_start.saturating_sub(1)
+            },
+            old: Vec::new(),
+            new: Vec::new(),
+        };
+
+        let mut last = Side::Both;
+        loop {
+            // A "\ No newline at end of file" marker applies to the line before it.
+            if lines.next_if(|(_, line)| line.starts_with('\\')).is_some() {
+                if matches!(last, Side::Old | Side::Both) {
+                    strip_line_ending(hunk.old.last_mut());
+                }
+                if matches!(last, Side::New | Side::Both) {
+                    strip_line_ending(hunk.new.last_mut());
+                }
+                continue;
+            }
+            if old_left == 0 && new_left == 0 {
+                break;
+            }
+
+            let (j, line) = lines.next().ok_or(Error::Malformed(i + 1))?;
+            let mut chars = line.chars();
+            // Some tools strip the leading space of empty context lines.
+            let (kind, content) = match line {
+                "\n" | "\r\n" => (Some(' '), line),
+                _ => (chars.next(), chars.as_str()),
+            };
+            match kind {
+                Some(' ') if old_left > 0 && new_left > 0 => {
+                    hunk.old.push(content.to_owned());
+                    hunk.new.push(content.to_owned());
+                    old_left -= 1;
+                    new_left -= 1;
+                    last = Side::Both;
+                }
+                Some('-') if old_left > 0 => {
+                    hunk.old.push(content.to_owned());
+                    old_left -= 1;
+                    last = Side::Old;
+                }
+                Some('+') if new_left > 0 => {
+                    hunk.new.push(content.to_owned());
+                    new_left -= 1;
+                    last = Side::New;
+                }
+                _ => return Err(Error::Malformed(j + 1)),
+            }
+        }
+
+        file.hunks.push(hunk);
+    }
+
+    files.retain(|file| !file.hunks.is_empty());
+    if files.is_empty() {
+        return Err(Error::Empty);
+    }
+    Ok(files)
+}
+
+/// Parses the path of a `---` or `+++` header, dropping a trailing timestamp and the given
+/// prefix git adds.
+fn parse_path(header: &str, prefix: &str) -> String {
+    let path = header.trim_end_matches(['\n', '\r']);
+    let path = path.split('\t').next().unwrap_or(path);
+    path.strip_prefix(prefix).unwrap_or(path).to_owned()
+}
+
+/// Parses `-start,len +start,len @@` into the old start line and the old and new lengths.
+fn parse_hunk_header(header: &str) -> Option<(usize, usize, usize)> {
+    fn parse_range(range: &str) -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, len)) => Some((start.parse().ok()?, len.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    }
+
+    let mut parts = header.split_whitespace();
+    let (old_start, old_len) = parse_range(parts.next()?.strip_prefix('-')?)?;
+    let (_, new_len) = parse_range(parts.next()?.strip_prefix('+')?)?;
+    Some((old_start, old_len, new_len))
+}
+
+fn strip_line_ending(line: Option<&mut String>) {
+    if let Some(line) = line {
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+    }
+}
+
+impl Hunk {
+    /// Finds the line at which the context and removed lines of the hunk match `text`. Like
+    /// `patch` without fuzz the lines must match exactly, but they may be offset from
+    /// `expected` when lines were added or removed before the hunk. The nearest match that
+    /// starts at or after `min_line` wins.
+    fn find(&self, text: &Rope, expected: usize, min_line: usize) -> Option<usize> {
+        let last_start = text.len_lines().checked_sub(self.old.len())?;
+        let expected = expected.max(min_line);
+        let matches_at = |start: usize| {
+            start <= last_start
+                && self
+                    .old
+                    .iter()
+                    .enumerate()
+                    .all(|(offset, line)| text.line(start + offset) == line.as_str())
+        };
+
+        for distance in 0.. {
+            let after = expected + distance;
+            let before = expected
+                .checked_sub(distance)
+                .filter(|&start| distance > 0 && start >= min_line);
+            if after > last_start && before.is_none() {
+                break;
+            }
+            if matches_at(after) {
+                return Some(after);
+            }
+            if let Some(before) = before.filter(|&start| matches_at(start)) {
+                return Some(before);
+            }
+        }
+        None
+    }
+}
+
+impl FilePatch {
+    /// Builds a transaction applying all hunks to `text`. Fails if the context and removed
+    /// lines of a hunk do not match the text, neither at the hunk's position nor offset by
+    /// some lines.
+    pub fn to_transaction(&self, text: &Rope) -> Result<Transaction> {
+        let mut changes = Vec::with_capacity(self.hunks.len());
+        let mut last_end_line = 0;
+        // Following hunks are most likely offset by as many lines as the previous one.
+        let mut drift = 0;
+
+        for (i, hunk) in self.hunks.iter().enumerate() {
+            let expected = hunk.old_start.saturating_add_signed(drift);
+            let start_line = hunk
+                .find(text, expected, last_end_line)
+                .ok_or(Error::HunkMismatch(i + 1))?;
+            let end_line = start_line + hunk.old.len();
+            drift = start_line as isize - hunk.old_start as isize;
+            last_end_line = end_line;
+
+            let from = text.line_to_char(start_line);
+            let to = text.line_to_char(end_line);
+
+            let new = hunk.new.concat();
+            changes.push((from, to, (!new.is_empty()).then(|| Tendril::from(new))));
+        }
+
+        Ok(Transaction::change(text, changes.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn apply(text: &str, patch: &str) -> Result<String> {
+        let files = parse(patch)?;
+        assert_eq!(files.len(), 1);
+        let mut text = Rope::from(text);
+        let transaction = files[0].to_transaction(&text)?;
+        assert!(transaction.apply(&mut text));
+        Ok(text.to_string())
+    }
+
+    #[test]
+    fn test_parse() {
+        let patch = "diff --git a/src/lib.rs b/src/lib.rs\n\
+                     index 0000000..1111111 100644\n\
+                     --- a/src/lib.rs\n\
+                     +++ b/src/lib.rs\n\
+                     @@ -1,2 +1,2 @@ fn main() {\n\
+                     \x20one\n\
+                     -two\n\
+                     +three\n";
+        assert_eq!(
+            parse(patch),
+            Ok(vec![FilePatch {
+                old_path: "src/lib.rs".to_owned(),
+                path: "src/lib.rs".to_owned(),
+                hunks: vec![Hunk {
+                    old_start: 0,
+                    old: vec!["one\n".to_owned(), "two\n".to_owned()],
+                    new: vec!["one\n".to_owned(), "three\n".to_owned()],
+                }],
+            }])
+        );
+
+        let patch = "--- a/old.rs\t2024-01-01 00:00:00\n+++ /dev/null\n@@ -1 +0,0 @@\n-one\n";
+        let files = parse(patch).unwrap();
+        assert_eq!(files[0].old_path, "old.rs");
+        assert_eq!(files[0].path, "/dev/null");
+
+        assert_eq!(parse("just some text\n"), Err(Error::Empty));
+        assert_eq!(
+            parse("+++ b/a\n@@ -1,2 +1,1 @@\n one\n"),
+            Err(Error::Malformed(2))
+        );
+        assert_eq!(
+            parse("+++ b/a\n@@ -1 +1 @@\n*one\n"),
+            Err(Error::Malformed(3))
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let text = "a\nb\nc\nd\ne\n";
+        let patch = "--- a\n+++ a\n\
+                     @@ -1,2 +1,2 @@\n a\n-b\n+B\n\
+                     @@ -4,0 +5,2 @@\n+x\n+y\n";
+        assert_eq!(apply(text, patch), Ok("a\nB\nc\nd\nx\ny\ne\n".to_owned()));
+
+        // Context that does not match the text is rejected.
+        let patch = "+++ a\n@@ -2,1 +2,1 @@\n-x\n+y\n";
+        assert_eq!(apply(text, patch), Err(Error::HunkMismatch(1)));
+    }
+
+    #[test]
+    fn test_apply_offset() {
+        let patch = "--- a\n+++ a\n\
+                     @@ -1,2 +1,2 @@\n a\n-b\n+B\n\
+                     @@ -4,2 +4,2 @@\n d\n-e\n+E\n";
+        // Lines were added before the first hunk, shifting both hunks.
+        assert_eq!(
+            apply("x\ny\na\nb\nc\nd\ne\n", patch),
+            Ok("x\ny\na\nB\nc\nd\nE\n".to_owned())
+        );
+        // Lines were removed between the hunks.
+        assert_eq!(apply("a\nb\nd\ne\n", patch), Ok("a\nB\nd\nE\n".to_owned()));
+        // Hunks are not reordered.
+        let patch = "--- a\n+++ a\n\
+                     @@ -3,1 +3,1 @@\n-c\n+C\n\
+                     @@ -4,1 +4,1 @@\n-a\n+A\n";
+        assert_eq!(apply("a\nb\nc\nd\n", patch), Err(Error::HunkMismatch(2)));
+    }
+
+    #[test]
+    fn test_apply_no_newline_at_end_of_file() {
+        let patch = "--- a\n+++ a\n\
+                     @@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+c\n";
+        assert_eq!(apply("a\nb", patch), Ok("a\nc\n".to_owned()));
+
+        let patch = "--- /dev/null\n+++ b/new\n\
+                     @@ -0,0 +1 @@\n+new\n\\ No newline at end of file\n";
+        assert_eq!(apply("", patch), Ok("new".to_owned()));
+    }
+}
@@ -403,6 +403,34 @@ pub fn language_for_shebang(&self, text: RopeSlice) -> Option<Language> {
         self.language_for_shebang_marker(marker)
     }
 
+    /// Detects the language from a vim (`vim: set ft=python:`) or emacs
+    /// (`-*- mode: python -*-`) modeline in the first or last lines of the text.
+    pub fn language_for_modeline(&self, text: RopeSlice) -> Option<Language> {
+        use once_cell::sync::Lazy;
+        use regex::Regex;
+        const MODELINE_LINES: usize = 5;
+        static VIM_MODELINE: Lazy<Regex> =
+            Lazy::new(|| Regex::new(r"\b(?:vi|vim|ex):.*?\b(?:ft|filetype)=([\w+-]+)").unwrap());
+        static EMACS_MODELINE: Lazy<Regex> = Lazy::new(|| {
+            Regex::new(r"-\*-\s*(?:.*?\bmode:\s*([\w+-]+).*?|([\w+-]+)\s*)-\*-").unwrap()
+        });
+
+        let len_lines = text.len_lines();
+        let head = 0..len_lines.min(MODELINE_LINES);
+        let tail = len_lines.saturating_sub(MODELINE_LINES).max(head.end)..len_lines;
+        head.chain(tail).find_map(|line_idx| {
+            let line: Cow<str> = text.line(line_idx).into();
+            let name = VIM_MODELINE
+                .captures(&line)
+                .or_else(|| EMACS_MODELINE.captures(&line))
+                .and_then(|captures| captures.get(1).or_else(|| captures.get(2)))?
+                .as_str()
+                .to_lowercase();
+            self.language_for_name(name.as_str())
+                .or_else(|| self.languages_by_shebang.get(&name).copied())
+        })
+    }
+
     fn language_for_shebang_marker(&self, marker: RopeSlice) -> Option<Language> {
         let shebang: Cow<str> = marker.into();
         self.languages_by_shebang.get(shebang.as_ref()).copied()
@@ -1205,6 +1233,22 @@ mod test {
 
     static LOADER: Lazy<Loader> = Lazy::new(crate::config::default_lang_loader);
 
+    #[test]
+    fn test_language_for_modeline() {
+        let detect = |text: &str| LOADER.language_for_modeline(Rope::from_str(text).slice(..));
+        let python = LOADER.language_for_name("python");
+        assert!(python.is_some());
+
+        assert_eq!(detect("# vim: set ft=python:\nprint()\n"), python);
+        assert_eq!(detect("print()\n# vi: filetype=python\n"), python);
+        assert_eq!(detect("# -*- mode: python; coding: utf-8 -*-\n"), python);
+        assert_eq!(detect("# -*- Python -*-\n"), python);
+        // names that are only known as shebangs
+        assert_eq!(detect("# vim: ft=sh\n"), LOADER.language_for_name("bash"));
+        assert_eq!(detect("# -*- coding: utf-8 -*-\n"), None);
+        assert_eq!(detect("# ft=python\n"), None);
+    }
+
     #[test]
     fn test_textobject_queries() {
         let query_str = r#"
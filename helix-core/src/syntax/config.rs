@@ -324,6 +324,7 @@ pub enum LanguageServerFeature {
     InlayHints,
     DocumentColors,
     CallHierarchy,
+    SelectionRange,
 }
 
 impl Display for LanguageServerFeature {
@@ -351,6 +352,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             InlayHints => "inlay-hints",
             DocumentColors => "document-colors",
             CallHierarchy => "call-hierarchy",
+            SelectionRange => "selection-range",
         };
         write!(f, "{feature}",)
     }
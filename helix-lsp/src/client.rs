@@ -407,6 +407,14 @@ pub fn supports_feature(&self, feature: LanguageServerFeature) -> bool {
                         | CallHierarchyServerCapability::Options(_)
                 )
             ),
+            LanguageServerFeature::SelectionRange => matches!(
+                capabilities.selection_range_provider,
+                Some(
+                    SelectionRangeProviderCapability::Simple(true)
+                        | SelectionRangeProviderCapability::Options(_)
+                        | SelectionRangeProviderCapability::RegistrationOptions(_)
+                )
+            ),
         }
     }
 
@@ -734,6 +742,9 @@ pub(crate) async fn initialize(&self, enable_snippets: bool) -> Result<lsp::Init
                     call_hierarchy: Some(lsp::DynamicRegistrationClientCapabilities {
                         dynamic_registration: Some(false),
                     }),
+                    selection_range: Some(lsp::SelectionRangeClientCapabilities {
+                        dynamic_registration: Some(false),
+                    }),
                     document_symbol: Some(lsp::DocumentSymbolClientCapabilities {
                         dynamic_registration: Some(false),
                         symbol_kind: Some(lsp::SymbolKindCapability {
@@ -1619,6 +1630,32 @@ pub fn document_symbols(
         Some(self.call::<lsp::request::DocumentSymbolRequest>(params))
     }
 
+    pub fn text_document_selection_range(
+        &self,
+        text_document: lsp::TextDocumentIdentifier,
+        positions: Vec<lsp::Position>,
+    ) -> Option<impl Future<Output = Result<Option<Vec<lsp::SelectionRange>>>>> {
+        let capabilities = self.capabilities.get().unwrap();
+
+        match capabilities.selection_range_provider {
+            Some(
+                lsp::SelectionRangeProviderCapability::Simple(true)
+                | lsp::SelectionRangeProviderCapability::Options(_)
+                | lsp::SelectionRangeProviderCapability::RegistrationOptions(_),
+            ) => (),
+            _ => return None,
+        }
+
+        let params = lsp::SelectionRangeParams {
+            text_document,
+            positions,
+            work_done_progress_params: lsp::WorkDoneProgressParams::default(),
+            partial_result_params: lsp::PartialResultParams::default(),
+        };
+
+        Some(self.call::<lsp::request::SelectionRangeRequest>(params))
+    }
+
     pub fn prepare_call_hierarchy(
         &self,
         text_document: lsp::TextDocumentIdentifier,
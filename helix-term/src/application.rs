@@ -21,7 +21,7 @@
 
 use crate::{
     args::Args,
-    compositor::{Compositor, Event},
+    compositor::{Compositor, Event, EventResult},
     config::Config,
     handlers,
     job::Jobs,
@@ -121,6 +121,9 @@ pub fn new(
         let mut compositor = Compositor::new(area);
         let config = Arc::new(ArcSwap::from_pointee(config));
         let handlers = handlers::setup(config.clone());
+        #[cfg(not(feature = "integration"))]
+        crate::crash_report::install_panic_hook(config.clone());
+        crate::crash_report::register_hooks();
         let mut editor = Editor::new(
             area,
             Arc::new(theme_loader),
@@ -232,6 +235,13 @@ pub fn new(
                 .unwrap_or_else(|_| editor.new_file(Action::VerticalSplit));
         }
 
+        #[cfg(not(feature = "integration"))]
+        if crate::crash_report::take_last_report().is_some() {
+            editor.set_error(
+                "Helix crashed during the last session, run :crash-report to open the report",
+            );
+        }
+
         #[cfg(windows)]
         let signals = futures_util::stream::empty();
         #[cfg(not(windows))]
@@ -340,7 +350,7 @@ pub async fn event_loop_until_idle<S>(&mut self, input_stream: &mut S) -> bool
                         helix_event::status::Severity::Error => Severity::Error,
                     };
                     // TODO: show multiple status messages at once to avoid clobbering
-                    self.editor.status_msg = Some((msg.message, severity));
+                    self.editor.set_status_msg(msg.message, severity);
                     helix_event::request_redraw();
                 }
                 Some(callback) = self.jobs.wait_futures.next() => {
@@ -692,6 +702,25 @@ pub async fn handle_editor_event(&mut self, event: EditorEvent) -> bool {
                     return true;
                 }
             }
+            EditorEvent::ChordTimer => {
+                self.editor.clear_chord_timer();
+                let mut cx = crate::compositor::Context {
+                    editor: &mut self.editor,
+                    jobs: &mut self.jobs,
+                    scroll: None,
+                };
+                let result = self
+                    .compositor
+                    .find::<ui::EditorView>()
+                    .expect("expected at least one EditorView")
+                    .handle_chord_timeout(&mut cx);
+                if let EventResult::Consumed(callback) = result {
+                    if let Some(callback) = callback {
+                        callback(&mut self.compositor, &mut cx);
+                    }
+                    self.render().await;
+                }
+            }
         }
 
         false
@@ -261,7 +261,8 @@ pub fn execute(&self, cx: &mut Context) {
                         cx.editor.set_error(format!("{}", e));
                     }
                 } else {
-                    cx.editor.set_error(format!("no such command: '{name}'"));
+                    cx.editor
+                        .set_error(typed::unknown_command_error(name).to_string());
                 }
             }
             Self::Static { fun, .. } => (fun)(cx),
@@ -318,6 +319,7 @@ pub fn doc(&self) -> &str {
         extend_visual_line_down, "Extend down",
         copy_selection_on_next_line, "Copy selection on next line",
         copy_selection_on_prev_line, "Copy selection on previous line",
+        select_block, "Select rectangular block spanned by selections",
         move_next_word_start, "Move to start of next word",
         move_prev_word_start, "Move to start of previous word",
         move_next_word_end, "Move to end of next word",
@@ -407,7 +409,11 @@ pub fn doc(&self) -> &str {
         file_explorer_in_current_buffer_directory, "Open file explorer at current buffer's directory",
         file_explorer_in_current_directory, "Open file explorer at current working directory",
         code_action, "Perform code action",
+        apply_preferred_code_action, "Apply preferred code action",
         buffer_picker, "Open buffer picker",
+        buffer_lines_picker, "Open picker over lines of all open buffers",
+        reopen_closed_buffer, "Reopen most recently closed buffer",
+        closed_buffer_picker, "Open recently closed buffer picker",
         jumplist_picker, "Open jumplist picker",
         symbol_picker, "Open symbol picker",
         syntax_symbol_picker, "Open symbol picker from syntax information",
@@ -447,6 +453,8 @@ pub fn doc(&self) -> &str {
         goto_last_accessed_file, "Goto last accessed file",
         goto_last_modified_file, "Goto last modified file",
         goto_last_modification, "Goto last modification",
+        goto_older_change, "Goto older change position",
+        goto_newer_change, "Goto newer change position",
         goto_line, "Goto line",
         goto_last_line, "Goto last line",
         extend_to_last_line, "Extend to last line",
@@ -454,6 +462,11 @@ pub fn doc(&self) -> &str {
         goto_last_diag, "Goto last diagnostic",
         goto_next_diag, "Goto next diagnostic",
         goto_prev_diag, "Goto previous diagnostic",
+        show_line_diagnostics, "Show all diagnostics on the cursor line",
+        toggle_bookmark, "Toggle bookmark on the current line",
+        goto_next_bookmark, "Goto next bookmark",
+        goto_prev_bookmark, "Goto previous bookmark",
+        bookmark_picker, "Open bookmark picker",
         goto_next_change, "Goto next change",
         goto_prev_change, "Goto previous change",
         goto_first_change, "Goto first change",
@@ -558,6 +571,7 @@ pub fn doc(&self) -> &str {
         align_view_top, "Align view top",
         align_view_center, "Align view center",
         align_view_bottom, "Align view bottom",
+        toggle_view_follows_cursor, "Toggle whether the view follows the cursor",
         scroll_up, "Scroll view up",
         scroll_down, "Scroll view down",
         match_brackets, "Goto matching bracket",
@@ -612,6 +626,7 @@ pub fn doc(&self) -> &str {
         command_palette, "Open command palette",
         goto_word, "Jump to a two-character label",
         extend_to_word, "Extend to a two-character label",
+        goto_link_hint, "Open a file path or URL in view by its two-character label",
         goto_next_tabstop, "Goto next snippet placeholder",
         goto_prev_tabstop, "Goto next snippet placeholder",
         rotate_selections_first, "Make the first selection your primary one",
@@ -2181,6 +2196,72 @@ fn copy_selection_on_next_line(cx: &mut Context) {
     copy_selection_on_line(cx, Direction::Forward)
 }
 
+/// Turn every selection into a block selection: one range per line between the anchor and
+/// the head, spanning the visual columns between them.
+fn select_block(cx: &mut Context) {
+    use helix_core::{pos_at_visual_coords, visual_coords_at_pos};
+
+    let (view, doc) = current!(cx.editor);
+    let text = doc.text().slice(..);
+    let tab_width = doc.tab_width();
+    let selection = doc.selection(view.id);
+    let mut ranges = SmallVec::with_capacity(selection.len());
+    let mut primary_index = 0;
+    for range in selection.iter() {
+        let is_primary = *range == selection.primary();
+
+        // The range is always head exclusive
+        let (head, anchor) = if range.anchor < range.head {
+            (range.head - 1, range.anchor)
+        } else if range.anchor == range.head {
+            // a point selects a single column
+            (range.head, range.head)
+        } else {
+            (range.head, range.anchor.saturating_sub(1))
+        };
+
+        let head_pos = visual_coords_at_pos(text, head, tab_width);
+        let anchor_pos = visual_coords_at_pos(text, anchor, tab_width);
+        let start_col = anchor_pos.col.min(head_pos.col);
+        let end_col = anchor_pos.col.max(head_pos.col);
+
+        // walk from the anchor towards the head so that the primary range ends up
+        // on the line of the head
+        let rows: Vec<usize> = if anchor_pos.row <= head_pos.row {
+            (anchor_pos.row..=head_pos.row).collect()
+        } else {
+            (head_pos.row..=anchor_pos.row).rev().collect()
+        };
+        for row in rows {
+            let line_end = line_end_char_index(&text, row);
+            let start = pos_at_visual_coords(text, Position::new(row, start_col), tab_width);
+            // skip lines that are too short
+            if start >= line_end || visual_coords_at_pos(text, start, tab_width).col != start_col {
+                continue;
+            }
+            let end = pos_at_visual_coords(text, Position::new(row, end_col), tab_width)
+                .min(line_end - 1);
+
+            if is_primary {
+                primary_index = ranges.len();
+            }
+            // Keep the direction of the original selection
+            let range = if anchor_pos.col <= head_pos.col {
+                Range::point(start).put_cursor(text, end, true)
+            } else {
+                Range::point(end).put_cursor(text, start, true)
+            };
+            ranges.push(range);
+        }
+    }
+
+    if ranges.is_empty() {
+        return;
+    }
+    let selection = Selection::new(ranges, primary_index);
+    doc.set_selection(view.id, selection);
+}
+
 fn select_all(cx: &mut Context) {
     let (view, doc) = current!(cx.editor);
 
@@ -3391,6 +3472,161 @@ struct BufferMeta<'a> {
     cx.push_layer(Box::new(overlaid(picker)));
 }
 
+fn buffer_lines_picker(cx: &mut Context) {
+    struct LineMeta {
+        id: DocumentId,
+        path: Option<std::sync::Arc<Path>>,
+        line: usize,
+        text: String,
+    }
+
+    let current = view!(cx.editor).doc;
+    let mut documents: Vec<_> = cx.editor.documents().collect();
+    // mru, so that lines of the current buffer are listed first
+    documents.sort_unstable_by_key(|doc| (doc.id() != current, std::cmp::Reverse(doc.focused_at)));
+    // ropes are cheap to clone, the lines themselves are collected in the background
+    let documents: Vec<_> = documents
+        .into_iter()
+        .map(|doc| {
+            let path = doc
+                .path()
+                .map(|path| helix_stdx::path::get_relative_path(path.as_path()))
+                .map(|path| std::sync::Arc::from(&*path));
+            (doc.id(), path, doc.text().clone())
+        })
+        .collect();
+
+    let columns = [
+        ui::PickerColumn::new("path", |item: &LineMeta, config: &PathStyleConfig| {
+            config.stylize(item.path.as_deref(), Some(item.line))
+        }),
+        ui::PickerColumn::new("contents", |item: &LineMeta, _| item.text.as_str().into()),
+    ];
+
+    let picker = Picker::new(
+        columns,
+        1, // contents
+        [],
+        PathStyleConfig::new(&cx.editor.theme),
+        |cx, meta, action| {
+            cx.editor.switch(meta.id, action);
+            let config = cx.editor.config();
+            let (view, doc) = (view_mut!(cx.editor), doc_mut!(cx.editor, &meta.id));
+            let text = doc.text().slice(..);
+            if meta.line >= text.len_lines() {
+                return;
+            }
+            doc.set_selection(view.id, Selection::point(text.line_to_char(meta.line)));
+            if action.align_view(view, doc.id()) {
+                view.ensure_cursor_in_view_center(doc, config.scrolloff);
+            }
+        },
+    )
+    .with_preview(|_editor, meta| Some((meta.id.into(), Some((meta.line, meta.line)))));
+    let injector = picker.injector();
+
+    std::thread::spawn(move || {
+        for (id, path, text) in documents {
+            for (line, text) in text.lines().enumerate() {
+                let text = text.to_string();
+                let text = text.trim_end();
+                if text.trim_start().is_empty() {
+                    continue;
+                }
+                let meta = LineMeta {
+                    id,
+                    path: path.clone(),
+                    line,
+                    text: text.to_owned(),
+                };
+                if injector.push(meta).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    cx.push_layer(Box::new(overlaid(picker)));
+}
+
+fn reopen_closed_buffer(cx: &mut Context) {
+    let Some((path, selection)) = cx.editor.closed_documents.back().cloned() else {
+        cx.editor.set_status("No closed buffers to reopen");
+        return;
+    };
+    reopen_closed_document(cx.editor, path, selection, Action::Replace);
+}
+
+/// Opens a closed document and restores its selection. The entry is only forgotten once the
+/// document could be opened, so that a failed attempt can be retried.
+fn reopen_closed_document(
+    editor: &mut Editor,
+    path: PathBuf,
+    selection: Selection,
+    action: Action,
+) {
+    let already_open = editor.document_by_path(&path).is_some();
+    if let Err(e) = editor.open(&path, action) {
+        editor.set_error(format!("Failed to open file '{}': {}", path.display(), e));
+        return;
+    }
+    editor
+        .closed_documents
+        .retain(|(closed, _)| *closed != path);
+    if already_open {
+        return;
+    }
+
+    let scrolloff = editor.config().scrolloff;
+    let (view, doc) = current!(editor);
+    let len = doc.text().len_chars();
+    let selection =
+        selection.transform(|range| Range::new(range.anchor.min(len), range.head.min(len)));
+    doc.set_selection(view.id, selection);
+    view.ensure_cursor_in_view_center(doc, scrolloff);
+}
+
+fn closed_buffer_picker(cx: &mut Context) {
+    struct ClosedMeta<'a> {
+        path: PathBuf,
+        display: Cow<'a, Path>,
+        selection: Selection,
+    }
+
+    let items: Vec<_> = cx
+        .editor
+        .closed_documents
+        .iter()
+        .rev()
+        .map(|(path, selection)| ClosedMeta {
+            path: path.clone(),
+            display: helix_stdx::path::get_relative_path(path.clone()),
+            selection: selection.clone(),
+        })
+        .collect();
+
+    if items.is_empty() {
+        cx.editor.set_status("No closed buffers to reopen");
+        return;
+    }
+
+    let columns = [ui::PickerColumn::new(
+        "path",
+        |item: &ClosedMeta, config: &PathStyleConfig| config.stylize(Some(&item.display), None),
+    )];
+
+    let picker = Picker::new(
+        columns,
+        0, // path
+        items,
+        PathStyleConfig::new(&cx.editor.theme),
+        |cx, meta, action| {
+            reopen_closed_document(cx.editor, meta.path.clone(), meta.selection.clone(), action);
+        },
+    )
+    .with_preview(|_editor, meta| Some((meta.path.as_path().into(), None)));
+    cx.push_layer(Box::new(overlaid(picker)));
+}
+
 fn jumplist_picker(cx: &mut Context) {
     struct JumpMeta<'a> {
         id: DocumentId,
@@ -4079,6 +4315,52 @@ fn goto_last_modification(cx: &mut Context) {
     }
 }
 
+fn goto_older_change(cx: &mut Context) {
+    goto_change_impl(cx, Direction::Backward)
+}
+
+fn goto_newer_change(cx: &mut Context) {
+    goto_change_impl(cx, Direction::Forward)
+}
+
+fn goto_change_impl(cx: &mut Context, direction: Direction) {
+    let count = cx.count();
+    let (view, doc) = current!(cx.editor);
+    let doc_id = doc.id();
+    let history = doc.history.get_mut();
+    let revision = history.current_revision();
+    // ordered from the oldest change to the most recent one
+    let positions = history.edit_positions();
+    // index counted from the most recent change
+    let current = match view.changelist_index {
+        Some((id, rev, index)) if id == doc_id && rev == revision => Some(index),
+        _ => None,
+    };
+    let index = match (current, direction) {
+        (Some(index), Direction::Backward) => Some(index + count),
+        (Some(index), Direction::Forward) => index.checked_sub(count),
+        (None, Direction::Backward) => Some(count - 1),
+        (None, Direction::Forward) => None,
+    };
+    let Some((index, pos)) =
+        index.and_then(|index| Some((index, *positions.iter().rev().nth(index)?)))
+    else {
+        cx.editor.set_status(match direction {
+            Direction::Backward => "Already at oldest change",
+            Direction::Forward => "Already at newest change",
+        });
+        return;
+    };
+    view.changelist_index = Some((doc_id, revision, index));
+    let text = doc.text().slice(..);
+    let selection = doc
+        .selection(view.id)
+        .clone()
+        .transform(|range| range.put_cursor(text, pos, cx.editor.mode == Mode::Select));
+    push_jump(view, doc);
+    doc.set_selection(view.id, selection);
+}
+
 fn goto_last_modified_file(cx: &mut Context) {
     let view = view!(cx.editor);
     let alternate_file = view
@@ -4200,6 +4482,182 @@ fn goto_prev_diag(cx: &mut Context) {
     cx.editor.apply_motion(motion)
 }
 
+fn show_line_diagnostics(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let line = doc
+        .selection(view.id)
+        .primary()
+        .cursor_line(doc.text().slice(..));
+
+    let diagnostics = doc.diagnostics().iter().filter(|diag| diag.line == line);
+    let lines = line_diagnostics_text(&cx.editor.theme, diagnostics);
+    if lines.is_empty() {
+        cx.editor.set_status("No diagnostics on this line");
+        return;
+    }
+
+    let contents = ui::Text::from(tui::text::Text::from(lines));
+    let popup = Popup::new("hover", contents).auto_close(true);
+    cx.replace_or_push_layer("hover", popup);
+}
+
+/// Lists diagnostics as styled text, one line per line of their messages. Messages are shown
+/// verbatim rather than as markdown since compilers often include code in them.
+fn line_diagnostics_text<'a>(
+    theme: &helix_view::Theme,
+    diagnostics: impl Iterator<Item = &'a helix_core::Diagnostic>,
+) -> Vec<Spans<'static>> {
+    use helix_core::diagnostic::{NumberOrString, Severity};
+
+    let mut lines = Vec::new();
+    for diag in diagnostics {
+        if !lines.is_empty() {
+            lines.push(Spans::default());
+        }
+
+        let (severity, style) = match diag.severity {
+            Some(Severity::Error) => ("error", theme.get("error")),
+            Some(Severity::Warning) | None => ("warning", theme.get("warning")),
+            Some(Severity::Info) => ("info", theme.get("info")),
+            Some(Severity::Hint) => ("hint", theme.get("hint")),
+        };
+        let code = diag.code.as_ref().map(|code| match code {
+            NumberOrString::Number(n) => n.to_string(),
+            NumberOrString::String(s) => s.clone(),
+        });
+        let origin = match (&diag.source, code) {
+            (Some(source), Some(code)) => format!(" {source}({code})"),
+            (Some(source), None) => format!(" {source}"),
+            (None, Some(code)) => format!(" {code}"),
+            (None, None) => String::new(),
+        };
+
+        let mut message = diag.message.lines();
+        lines.push(Spans::from(vec![
+            Span::styled(severity, style),
+            Span::styled(origin, theme.get("ui.text.inactive")),
+            Span::raw(": "),
+            Span::raw(message.next().unwrap_or_default().to_string()),
+        ]));
+        lines.extend(message.map(|line| Spans::from(line.to_string())));
+    }
+    lines
+}
+
+fn toggle_bookmark(cx: &mut Context) {
+    let (view, doc) = current!(cx.editor);
+    let line = doc
+        .selection(view.id)
+        .primary()
+        .cursor_line(doc.text().slice(..));
+    if doc.toggle_bookmark(line) {
+        cx.editor
+            .set_status(format!("Bookmarked line {}", line + 1));
+    } else {
+        cx.editor
+            .set_status(format!("Removed bookmark on line {}", line + 1));
+    }
+}
+
+fn goto_next_bookmark(cx: &mut Context) {
+    goto_bookmark_impl(cx, Direction::Forward)
+}
+
+fn goto_prev_bookmark(cx: &mut Context) {
+    goto_bookmark_impl(cx, Direction::Backward)
+}
+
+fn goto_bookmark_impl(cx: &mut Context, direction: Direction) {
+    let motion = move |editor: &mut Editor| {
+        let extend = editor.mode == Mode::Select;
+        let (view, doc) = current!(editor);
+        let text = doc.text().slice(..);
+        let cursor_line = doc.selection(view.id).primary().cursor_line(text);
+
+        let line = match direction {
+            Direction::Forward => doc.bookmarked_lines().find(|&line| line > cursor_line),
+            Direction::Backward => doc
+                .bookmarked_lines()
+                .take_while(|&line| line < cursor_line)
+                .last(),
+        };
+        let Some(line) = line else {
+            return;
+        };
+        let pos = text.line_to_char(line);
+        let selection = doc
+            .selection(view.id)
+            .clone()
+            .transform(|range| range.put_cursor(text, pos, extend));
+        push_jump(view, doc);
+        doc.set_selection(view.id, selection);
+    };
+    cx.editor.apply_motion(motion);
+}
+
+fn bookmark_picker(cx: &mut Context) {
+    struct BookmarkMeta<'a> {
+        id: DocumentId,
+        path: Option<Cow<'a, Path>>,
+        line: usize,
+        text: String,
+    }
+
+    let items: Vec<_> = cx
+        .editor
+        .documents()
+        .flat_map(|doc| {
+            let text = doc.text().slice(..);
+            doc.bookmarked_lines().map(move |line| BookmarkMeta {
+                id: doc.id(),
+                path: doc
+                    .path()
+                    .map(ToOwned::to_owned)
+                    .map(helix_stdx::path::get_relative_path),
+                line,
+                text: text.line(line).to_string().trim_end().to_owned(),
+            })
+        })
+        .collect();
+
+    if items.is_empty() {
+        cx.editor.set_status("No bookmarks");
+        return;
+    }
+
+    let columns = [
+        ui::PickerColumn::new("path", |item: &BookmarkMeta, config: &PathStyleConfig| {
+            config.stylize(item.path.as_deref(), Some(item.line))
+        }),
+        ui::PickerColumn::new("contents", |item: &BookmarkMeta, _| {
+            item.text.as_str().into()
+        }),
+    ];
+
+    let picker = Picker::new(
+        columns,
+        1, // contents
+        items,
+        PathStyleConfig::new(&cx.editor.theme),
+        |cx, meta, action| {
+            cx.editor.switch(meta.id, action);
+            let config = cx.editor.config();
+            let (view, doc) = (view_mut!(cx.editor), doc_mut!(cx.editor, &meta.id));
+            let text = doc.text().slice(..);
+            if meta.line >= text.len_lines() {
+                return;
+            }
+            let pos = text.line_to_char(meta.line);
+            doc.set_selection(view.id, Selection::point(pos));
+            if action.align_view(view, doc.id()) {
+                view.ensure_cursor_in_view_center(doc, config.scrolloff);
+            }
+        },
+    )
+    .with_preview(|_editor, meta| Some((meta.id.into(), Some((meta.line, meta.line)))));
+    cx.push_layer(Box::new(overlaid(picker)));
+}
+
 fn goto_first_change(cx: &mut Context) {
     goto_first_change_impl(cx, false);
 }
@@ -5729,7 +6187,20 @@ fn reverse_selection_contents(cx: &mut Context) {
 
 // tree sitter node selection
 
+/// Whether `expand_selection` and `shrink_selection` should use the selection ranges of a
+/// language server instead of the syntax tree.
+fn use_lsp_selection_ranges(editor: &Editor) -> bool {
+    let doc = doc!(editor);
+    editor.config().lsp.selection_ranges
+        && doc.has_language_server_with_feature(LanguageServerFeature::SelectionRange)
+}
+
 fn expand_selection(cx: &mut Context) {
+    if use_lsp_selection_ranges(cx.editor) {
+        expand_selection_range(cx);
+        return;
+    }
+
     let motion = |editor: &mut Editor| {
         let (view, doc) = current!(editor);
 
@@ -5752,6 +6223,18 @@ fn expand_selection(cx: &mut Context) {
 }
 
 fn shrink_selection(cx: &mut Context) {
+    let (view, doc) = current_ref!(cx.editor);
+    let restores_previous = view
+        .object_selections
+        .last()
+        .is_some_and(|prev_selection| doc.selection(view.id).contains(prev_selection));
+    if !restores_previous && use_lsp_selection_ranges(cx.editor) {
+        // the saved selections are not enclosed by the current one and cannot be restored
+        view_mut!(cx.editor).object_selections.clear();
+        shrink_selection_range(cx);
+        return;
+    }
+
     let motion = |editor: &mut Editor| {
         let (view, doc) = current!(editor);
         let current_selection = doc.selection(view.id);
@@ -6103,6 +6586,18 @@ fn align_view_bottom(cx: &mut Context) {
     align_view(doc, view, Align::Bottom);
 }
 
+fn toggle_view_follows_cursor(cx: &mut Context) {
+    let scrolloff = cx.editor.config().scrolloff;
+    let (view, doc) = current!(cx.editor);
+    view.follow_cursor = !view.follow_cursor;
+    if view.follow_cursor {
+        view.ensure_cursor_in_view(doc, scrolloff);
+        cx.editor.set_status("View follows cursor");
+    } else {
+        cx.editor.set_status("View pinned");
+    }
+}
+
 fn align_view_middle(cx: &mut Context) {
     let (view, doc) = current!(cx.editor);
     let inner_width = view.inner_width(doc);
@@ -6983,7 +7478,14 @@ fn extend_to_word(cx: &mut Context) {
     jump_to_word(cx, Movement::Extend)
 }
 
-fn jump_to_label(cx: &mut Context, labels: Vec<Range>, behaviour: Movement) {
+/// Labels each of the given ranges and selects the one whose label is typed next, then calls
+/// `on_jump` with the new selection in place.
+fn jump_to_label(
+    cx: &mut Context,
+    labels: Vec<Range>,
+    behaviour: Movement,
+    on_jump: fn(&mut Context),
+) {
     let doc = doc!(cx.editor);
     let alphabet = &cx.editor.config().jump_label_alphabet;
     if labels.is_empty() {
@@ -7070,6 +7572,7 @@ fn jump_to_label(cx: &mut Context, labels: Vec<Range>, behaviour: Movement) {
                 let view = view_mut!(cx.editor, view_id);
                 push_jump(view, doc);
                 doc.set_selection(view_id, range.into());
+                on_jump(cx);
             }
         });
     });
@@ -7170,7 +7673,55 @@ fn jump_to_word(cx: &mut Context, behaviour: Movement) {
             break;
         }
     }
-    jump_to_label(cx, words, behaviour)
+    jump_to_label(cx, words, behaviour, |_| ())
+}
+
+fn goto_link_hint(cx: &mut Context) {
+    // Calculate the jump candidates: file paths and URLs visible in the view.
+    let jump_label_limit = {
+        let alphabet = &cx.editor.config().jump_label_alphabet;
+        alphabet.len() * alphabet.len()
+    };
+    if jump_label_limit == 0 {
+        return;
+    }
+
+    let (view, doc) = current_ref!(cx.editor);
+    let text = doc.text().slice(..);
+
+    // This is not necessarily exact if there is virtual text like soft wrap.
+    // It's ok though because the extra jump labels will not be rendered.
+    let start = text.line_to_char(text.char_to_line(doc.view_offset(view.id).anchor));
+    let end = text.line_to_char(view.estimate_last_doc_line(doc) + 1);
+    let start_byte = text.char_to_byte(start);
+
+    let links: Vec<_> = helix_stdx::path::find_paths(text.slice(start..end), false)
+        .filter(|range| {
+            // The path regex also matches lone separators, like the slashes of a `//` comment.
+            let link: Cow<str> = text
+                .byte_slice(start_byte + range.start..start_byte + range.end)
+                .into();
+            Url::parse(&link).is_ok()
+                || Path::new(&*link)
+                    .components()
+                    .any(|component| matches!(component, std::path::Component::Normal(_)))
+        })
+        .take(jump_label_limit)
+        .map(|range| {
+            Range::new(
+                text.byte_to_char(start_byte + range.start),
+                text.byte_to_char(start_byte + range.end),
+            )
+        })
+        .collect();
+
+    if links.is_empty() {
+        cx.editor.set_status("No file paths or URLs in view");
+        return;
+    }
+    jump_to_label(cx, links, Movement::Move, |cx| {
+        goto_file_impl(cx, Action::Replace)
+    })
 }
 
 fn lsp_or_syntax_symbol_picker(cx: &mut Context) {
@@ -7203,3 +7754,68 @@ fn lsp_or_syntax_workspace_symbol_picker(cx: &mut Context) {
         syntax_workspace_symbol_picker(cx);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use helix_core::diagnostic::{
+        Diagnostic, DiagnosticProvider, LanguageServerId, NumberOrString, Range, Severity,
+    };
+
+    fn diagnostic(
+        message: &str,
+        severity: Severity,
+        source: Option<&str>,
+        code: Option<NumberOrString>,
+    ) -> Diagnostic {
+        Diagnostic {
+            range: Range { start: 0, end: 1 },
+            ends_at_word: false,
+            starts_at_word: false,
+            zero_width: false,
+            line: 0,
+            message: message.to_owned(),
+            severity: Some(severity),
+            code,
+            provider: DiagnosticProvider::Lsp {
+                server_id: LanguageServerId::default(),
+                identifier: None,
+            },
+            tags: Vec::new(),
+            source: source.map(str::to_owned),
+            data: None,
+        }
+    }
+
+    #[test]
+    fn test_line_diagnostics_text() {
+        let theme = helix_view::Theme::default();
+        let diagnostics = [
+            diagnostic(
+                "mismatched types\nexpected `usize`, found `&str`",
+                Severity::Error,
+                Some("rustc"),
+                Some(NumberOrString::String("E0308".to_owned())),
+            ),
+            diagnostic("use *pointer* or `_x`", Severity::Warning, None, None),
+            diagnostic("consider borrowing", Severity::Hint, Some("rustc"), None),
+        ];
+
+        let lines: Vec<String> = line_diagnostics_text(&theme, diagnostics.iter())
+            .iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "error rustc(E0308): mismatched types",
+                "expected `usize`, found `&str`",
+                "",
+                "warning: use *pointer* or `_x`",
+                "",
+                "hint rustc: consider borrowing",
+            ]
+        );
+        assert!(line_diagnostics_text(&theme, std::iter::empty()).is_empty());
+    }
+}
@@ -5,7 +5,7 @@
         self, CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionTriggerKind,
         DiagnosticSeverity, NumberOrString,
     },
-    util::{diagnostic_to_lsp_diagnostic, lsp_range_to_range, range_to_lsp_range},
+    util::{diagnostic_to_lsp_diagnostic, lsp_range_to_range, pos_to_lsp_pos, range_to_lsp_range},
     Client, LanguageServerId, OffsetEncoding,
 };
 use tokio_stream::StreamExt;
@@ -596,39 +596,9 @@ fn format(&self, _data: &Self::Data) -> Row<'_> {
 }
 
 pub fn code_action(cx: &mut Context) {
-    let (view, doc) = current!(cx.editor);
-
-    let selection_range = doc.selection(view.id).primary();
-
-    let mut futures: FuturesUnordered<_> =
-        code_actions_for_range(doc, selection_range, None, CodeActionTriggerKind::INVOKED)
-            .into_iter()
-            .map(|(request, ls_id)| async move {
-                let Some(mut actions) = request.await? else {
-                    return anyhow::Ok(Vec::new());
-                };
-
-                // remove disabled code actions
-                actions.retain(|action| {
-                    matches!(
-                        action,
-                        CodeActionOrCommand::Command(_)
-                            | CodeActionOrCommand::CodeAction(CodeAction { disabled: None, .. })
-                    )
-                });
-
-                Ok(actions
-                    .into_iter()
-                    .map(|lsp_item| CodeActionItem::lsp(ls_id, lsp_item))
-                    .collect())
-            })
-            .collect();
-
-    if futures.is_empty() {
-        cx.editor
-            .set_error("No configured language server supports code actions");
+    let Some(mut futures) = code_action_futures(cx, false) else {
         return;
-    }
+    };
 
     cx.jobs.callback(async move {
         let mut actions = Vec::new();
@@ -669,6 +639,91 @@ pub fn code_action(cx: &mut Context) {
     });
 }
 
+/// Applies the highest priority code action the language servers marked as preferred, without
+/// opening the code action menu.
+pub fn apply_preferred_code_action(cx: &mut Context) {
+    let Some(mut futures) = code_action_futures(cx, true) else {
+        return;
+    };
+
+    cx.jobs.callback(async move {
+        let mut actions = Vec::new();
+
+        while let Some(output) = futures.next().await {
+            match output {
+                Ok(mut items) => actions.append(&mut items),
+                Err(err) => log::error!("while gathering code actions: {err}"),
+            }
+        }
+
+        actions.sort_by_key(|action| std::cmp::Reverse(action.priority));
+
+        let call = move |editor: &mut Editor| match actions.first() {
+            Some(action) => action.execute(editor),
+            None => editor.set_error("No preferred code action available"),
+        };
+
+        Ok(Callback::Editor(Box::new(call)))
+    });
+}
+
+/// Requests code actions for the primary selection from every language server supporting them,
+/// dropping disabled actions and, if `preferred_only` is set, those not marked as preferred.
+///
+/// Returns `None` and reports an error if no language server supports code actions.
+fn code_action_futures(
+    cx: &mut Context,
+    preferred_only: bool,
+) -> Option<FuturesUnordered<impl Future<Output = anyhow::Result<Vec<CodeActionItem>>>>> {
+    let (view, doc) = current!(cx.editor);
+
+    let selection_range = doc.selection(view.id).primary();
+
+    let futures: FuturesUnordered<_> =
+        code_actions_for_range(doc, selection_range, None, CodeActionTriggerKind::INVOKED)
+            .into_iter()
+            .map(|(request, ls_id)| async move {
+                let Some(mut actions) = request.await? else {
+                    return anyhow::Ok(Vec::new());
+                };
+
+                // remove disabled code actions
+                actions.retain(|action| {
+                    matches!(
+                        action,
+                        CodeActionOrCommand::Command(_)
+                            | CodeActionOrCommand::CodeAction(CodeAction { disabled: None, .. })
+                    )
+                });
+
+                if preferred_only {
+                    actions.retain(|action| {
+                        matches!(
+                            action,
+                            CodeActionOrCommand::CodeAction(CodeAction {
+                                is_preferred: Some(true),
+                                ..
+                            })
+                        )
+                    });
+                }
+
+                Ok(actions
+                    .into_iter()
+                    .map(|lsp_item| CodeActionItem::lsp(ls_id, lsp_item))
+                    .collect())
+            })
+            .collect();
+
+    if futures.is_empty() {
+        cx.editor
+            .set_error("No configured language server supports code actions");
+        return None;
+    }
+
+    Some(futures)
+}
+
 // Extracting this to a type alias would require boxing this future
 #[allow(clippy::type_complexity)]
 pub(crate) fn code_actions_for_range(
@@ -1329,6 +1384,89 @@ pub fn select_references_to_symbol_under_cursor(cx: &mut Context) {
     );
 }
 
+/// Expands every selection to the smallest enclosing selection range of the language server,
+/// saving the current selection so that `shrink_selection` can restore it.
+pub fn expand_selection_range(cx: &mut Context) {
+    selection_range_impl(cx, true)
+}
+
+/// Shrinks every selection to the largest selection range of the language server it encloses.
+pub fn shrink_selection_range(cx: &mut Context) {
+    selection_range_impl(cx, false)
+}
+
+fn selection_range_impl(cx: &mut Context, expand: bool) {
+    let (view, doc) = current!(cx.editor);
+    let language_server =
+        language_server_with_feature!(cx.editor, doc, LanguageServerFeature::SelectionRange);
+    let offset_encoding = language_server.offset_encoding();
+    let text = doc.text();
+    let selection = doc.selection(view.id).clone();
+    let positions = selection
+        .iter()
+        .map(|range| pos_to_lsp_pos(text, range.cursor(text.slice(..)), offset_encoding))
+        .collect();
+    let future = language_server
+        .text_document_selection_range(doc.identifier(), positions)
+        .unwrap();
+    let view_id = view.id;
+    let doc_id = doc.id();
+
+    cx.callback(
+        future,
+        move |editor, _compositor, response: Option<Vec<lsp::SelectionRange>>| {
+            let Some(selection_ranges) = response else {
+                return;
+            };
+            if !editor.tree.contains(view_id) {
+                return;
+            }
+            let Some(doc) = editor.documents.get_mut(&doc_id) else {
+                return;
+            };
+            // the selection changed while waiting for the response
+            if doc.selections().get(&view_id) != Some(&selection) {
+                return;
+            }
+
+            let text = doc.text();
+            let ranges = selection.iter().enumerate().map(|(i, range)| {
+                let Some(selection_range) = selection_ranges.get(i) else {
+                    return *range;
+                };
+                // from the innermost range to the outermost one
+                let mut candidates = std::iter::successors(Some(selection_range), |candidate| {
+                    candidate.parent.as_deref()
+                })
+                .filter_map(|candidate| lsp_range_to_range(text, candidate.range, offset_encoding));
+                let new_range = if expand {
+                    candidates.find(|candidate| {
+                        candidate.contains_range(range) && candidate.len() > range.len()
+                    })
+                } else {
+                    candidates
+                        .take_while(|candidate| candidate.len() < range.len())
+                        .filter(|candidate| range.contains_range(candidate))
+                        .last()
+                };
+                new_range.map_or(*range, |new_range| {
+                    new_range.with_direction(range.direction())
+                })
+            });
+            let new_selection = Selection::new(ranges.collect(), selection.primary_index());
+            if new_selection == selection {
+                return;
+            }
+
+            if expand {
+                // save current selection so it can be restored using shrink_selection
+                view_mut!(editor, view_id).object_selections.push(selection);
+            }
+            doc.set_selection(view_id, new_selection);
+        },
+    );
+}
+
 pub fn compute_inlay_hints_for_all_views(editor: &mut Editor, jobs: &mut crate::job::Jobs) {
     if !editor.config().lsp.display_inlay_hints {
         return;
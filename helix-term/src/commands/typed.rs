@@ -606,12 +606,29 @@ fn force_write_buffer_close(
     buffer_close_by_ids_impl(cx, &document_ids, false)
 }
 
-fn new_file(cx: &mut compositor::Context, _args: Args, event: PromptEvent) -> anyhow::Result<()> {
+fn new_file(cx: &mut compositor::Context, args: Args, event: PromptEvent) -> anyhow::Result<()> {
     if event != PromptEvent::Validate {
         return Ok(());
     }
 
-    cx.editor.new_file(Action::Replace);
+    let loader = cx.editor.syn_loader.load();
+    let language = args
+        .first()
+        .map(|language_id| {
+            loader
+                .language_for_name(language_id)
+                .map(|language| loader.language(language).config().clone())
+                .ok_or_else(|| anyhow!("invalid language id: {}", language_id))
+        })
+        .transpose()?;
+
+    let doc_id = cx.editor.new_file(Action::Replace);
+    if language.is_some() {
+        let doc = doc_mut!(cx.editor, &doc_id);
+        doc.set_language(language, &loader);
+        doc.detect_indent_and_line_ending();
+        cx.editor.refresh_language_servers(doc_id);
+    }
 
     Ok(())
 }
@@ -1923,6 +1940,58 @@ fn lsp_stop(cx: &mut compositor::Context, args: Args, event: PromptEvent) -> any
     Ok(())
 }
 
+fn lsp_resync(cx: &mut compositor::Context, args: Args, event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+    let doc = doc!(cx.editor);
+    let url = doc
+        .url()
+        .context("Can't resync a document without a path")?;
+
+    let language_servers: Vec<_> = doc.language_servers().collect();
+    if !args.is_empty() {
+        let names: Vec<_> = language_servers.iter().map(|ls| ls.name()).collect();
+        let invalid: Vec<&str> = args
+            .iter()
+            .map(|arg| arg.as_ref())
+            .filter(|name| !names.contains(name))
+            .collect();
+        if !invalid.is_empty() {
+            let s = if invalid.len() == 1 { "" } else { "s" };
+            bail!("Unknown language server{s}: {}", invalid.join(", "));
+        }
+    }
+
+    let language_id = doc.language_id().map(ToOwned::to_owned).unwrap_or_default();
+    let mut resynced = 0;
+    for language_server in language_servers
+        .into_iter()
+        .filter(|ls| args.is_empty() || args.iter().any(|arg| arg == ls.name()))
+    {
+        // Reopening the document makes the server drop whatever state it tracked for it and
+        // start over from the full text.
+        language_server.text_document_did_close(doc.identifier());
+        language_server.text_document_did_open(
+            url.clone(),
+            doc.version(),
+            doc.text(),
+            language_id.clone(),
+        );
+        resynced += 1;
+    }
+
+    if resynced == 0 {
+        bail!("No active language servers for the current document");
+    }
+    let s = if resynced == 1 { "" } else { "s" };
+    cx.editor.set_status(format!(
+        "Resynced document with {resynced} language server{s}"
+    ));
+
+    Ok(())
+}
+
 fn tree_sitter_scopes(
     cx: &mut compositor::Context,
     _args: Args,
@@ -2565,6 +2634,21 @@ fn open_config(
     Ok(())
 }
 
+fn open_crash_report(
+    cx: &mut compositor::Context,
+    _args: Args,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let report = crate::crash_report::last_report_file();
+    ensure!(report.exists(), "No crash report");
+    cx.editor.open(&report, Action::Replace)?;
+    Ok(())
+}
+
 fn open_workspace_config(
     cx: &mut compositor::Context,
     _args: Args,
@@ -2588,6 +2672,57 @@ fn open_log(cx: &mut compositor::Context, _args: Args, event: PromptEvent) -> an
     Ok(())
 }
 
+fn show_messages(
+    cx: &mut compositor::Context,
+    _args: Args,
+    event: PromptEvent,
+) -> anyhow::Result<()> {
+    use helix_core::diagnostic::Severity;
+
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    if cx.editor.status_history.is_empty() {
+        cx.editor.set_status("No messages");
+        return Ok(());
+    }
+
+    let theme = &cx.editor.theme;
+    // newest first, so that the most recent messages are visible without scrolling
+    let lines: Vec<Spans> = cx
+        .editor
+        .status_history
+        .iter()
+        .rev()
+        .flat_map(|(message, severity)| {
+            let style = match severity {
+                Severity::Error => theme.get("error"),
+                Severity::Warning => theme.get("warning"),
+                Severity::Info => theme.get("info"),
+                Severity::Hint => theme.get("hint"),
+            };
+            message
+                .lines()
+                .map(move |line| Span::styled(line.to_string(), style).into())
+        })
+        .collect();
+    let contents = ui::Text::from(tui::text::Text::from(lines));
+
+    let callback = async move {
+        let call: job::Callback = Callback::EditorCompositor(Box::new(
+            move |_editor: &mut Editor, compositor: &mut Compositor| {
+                let popup = Popup::new("messages", contents).auto_close(true);
+                compositor.replace_or_push("messages", popup);
+            },
+        ));
+        Ok(call)
+    };
+    cx.jobs.callback(callback);
+
+    Ok(())
+}
+
 fn refresh_config(
     cx: &mut compositor::Context,
     _args: Args,
@@ -2737,6 +2872,85 @@ fn reset_diff_change(
     Ok(())
 }
 
+fn apply_patch(cx: &mut compositor::Context, args: Args, event: PromptEvent) -> anyhow::Result<()> {
+    if event != PromptEvent::Validate {
+        return Ok(());
+    }
+
+    let patch = match args.first() {
+        Some(path) => {
+            let path = helix_stdx::path::expand_tilde(Path::new(path));
+            std::fs::read_to_string(&path)
+                .map_err(|err| anyhow!("error reading {}: {}", path.display(), err))?
+        }
+        None => cx
+            .editor
+            .registers
+            .read('+', cx.editor)
+            .and_then(|mut values| values.next().map(|value| value.into_owned()))
+            .ok_or_else(|| anyhow!("Clipboard is empty"))?,
+    };
+
+    let files = helix_core::patch::parse(&patch)?;
+    let mut applied = 0;
+    let mut failures = Vec::new();
+
+    for file in &files {
+        if file.path == "/dev/null" {
+            failures.push(format!(
+                "{}: deleting files is not supported",
+                file.old_path
+            ));
+            continue;
+        }
+
+        // Patches name files relative to the root of the repository they were made in.
+        let path =
+            helix_stdx::path::canonicalize(helix_loader::find_workspace().0.join(&file.path));
+        let was_open = cx.editor.document_by_path(&path).is_some();
+        let doc_id = match cx.editor.open(&path, Action::Load) {
+            Ok(doc_id) => doc_id,
+            Err(err) => {
+                failures.push(format!("{}: {}", file.path, err));
+                continue;
+            }
+        };
+
+        let transaction = match file.to_transaction(doc!(cx.editor, &doc_id).text()) {
+            Ok(transaction) => transaction,
+            Err(err) => {
+                failures.push(format!("{}: {}", file.path, err));
+                if !was_open {
+                    let _ = cx.editor.discard_document(doc_id, true);
+                }
+                continue;
+            }
+        };
+
+        let view_id = cx.editor.get_synced_view_id(doc_id);
+        let doc = doc_mut!(cx.editor, &doc_id);
+        let view = view_mut!(cx.editor, view_id);
+        doc.apply(&transaction, view.id);
+        doc.append_changes_to_history(view);
+        applied += 1;
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "Applied patch to {applied} of {} file{}, failed: {}",
+            files.len(),
+            if files.len() == 1 { "" } else { "s" },
+            failures.join(", ")
+        );
+    }
+
+    cx.editor.set_status(format!(
+        "Applied patch to {applied} file{}",
+        if applied == 1 { "" } else { "s" }
+    ));
+    Ok(())
+}
+
 fn clear_register(
     cx: &mut compositor::Context,
     args: Args,
@@ -3194,11 +3408,11 @@ fn noop(_cx: &mut compositor::Context, _args: Args, _event: PromptEvent) -> anyh
     TypableCommand {
         name: "new",
         aliases: &["n"],
-        doc: "Create a new scratch buffer.",
+        doc: "Create a new scratch buffer, optionally using the given language.",
         fun: new_file,
-        completer: CommandCompleter::none(),
+        completer: CommandCompleter::positional(&[completers::language]),
         signature: Signature {
-            positionals: (0, Some(0)),
+            positionals: (0, Some(1)),
             ..Signature::DEFAULT
         },
     },
@@ -3664,6 +3878,17 @@ fn noop(_cx: &mut compositor::Context, _args: Args, _event: PromptEvent) -> anyh
             ..Signature::DEFAULT
         },
     },
+    TypableCommand {
+        name: "lsp-resync",
+        aliases: &[],
+        doc: "Reopens the current document with the given language servers, or all language servers that are used by the current file if no arguments are supplied, resending its full text",
+        fun: lsp_resync,
+        completer: CommandCompleter::all(completers::active_language_servers),
+        signature: Signature {
+            positionals: (0, None),
+            ..Signature::DEFAULT
+        },
+    },
     TypableCommand {
         name: "tree-sitter-scopes",
         aliases: &[],
@@ -3934,6 +4159,28 @@ fn noop(_cx: &mut compositor::Context, _args: Args, _event: PromptEvent) -> anyh
             ..Signature::DEFAULT
         },
     },
+    TypableCommand {
+        name: "crash-report",
+        aliases: &[],
+        doc: "Open the report written when helix last crashed.",
+        fun: open_crash_report,
+        completer: CommandCompleter::none(),
+        signature: Signature {
+            positionals: (0, Some(0)),
+            ..Signature::DEFAULT
+        },
+    },
+    TypableCommand {
+        name: "messages",
+        aliases: &[],
+        doc: "Show the status messages of this session, newest first.",
+        fun: show_messages,
+        completer: CommandCompleter::none(),
+        signature: Signature {
+            positionals: (0, Some(0)),
+            ..Signature::DEFAULT
+        },
+    },
     TypableCommand {
         name: "insert-output",
         aliases: &[],
@@ -3985,6 +4232,17 @@ fn noop(_cx: &mut compositor::Context, _args: Args, _event: PromptEvent) -> anyh
             ..Signature::DEFAULT
         },
     },
+    TypableCommand {
+        name: "apply-patch",
+        aliases: &[],
+        doc: "Apply a unified diff read from the given file, or from the clipboard if no file is given, to the files it names, relative to the workspace root. Hunks must match exactly but may be offset from the lines given in the patch. No preview is shown, the changes can be undone per file.",
+        fun: apply_patch,
+        completer: CommandCompleter::positional(&[completers::filename]),
+        signature: Signature {
+            positionals: (0, Some(1)),
+            ..Signature::DEFAULT
+        },
+    },
     TypableCommand {
         name: "clear-register",
         aliases: &[],
@@ -4139,12 +4397,49 @@ fn execute_command_line(
     }
 
     match typed::TYPABLE_COMMAND_MAP.get(command) {
-        Some(cmd) => execute_command(cx, cmd, rest, event),
-        None if event == PromptEvent::Validate => Err(anyhow!("no such command: '{command}'")),
+        Some(cmd) => {
+            if event == PromptEvent::Validate {
+                crate::crash_report::record_command(format!(":{}", cmd.name));
+            }
+            execute_command(cx, cmd, rest, event)
+        }
+        None if event == PromptEvent::Validate => Err(unknown_command_error(command)),
         None => Ok(()),
     }
 }
 
+/// Builds the error for an unknown command, suggesting the closest known command name if
+/// there is one within a small edit distance.
+pub(super) fn unknown_command_error(command: &str) -> anyhow::Error {
+    let max_distance = (command.chars().count() / 3).max(1);
+    let suggestion = TYPABLE_COMMAND_MAP
+        .keys()
+        .map(|&name| (edit_distance(command, name), name))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min();
+
+    match suggestion {
+        Some((_, name)) => anyhow!("no such command: '{command}', did you mean '{name}'?"),
+        None => anyhow!("no such command: '{command}'"),
+    }
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
 pub(super) fn execute_command(
     cx: &mut compositor::Context,
     cmd: &TypableCommand,
@@ -0,0 +1,138 @@
+//! Writes a report to the cache directory when the editor panics, with the paths of the open
+//! documents, the last executed commands and the configuration in use. The report is mentioned
+//! on the next launch and can be opened with `:crash-report` so that it can be attached to a
+//! bug report.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::Write,
+    panic::PanicHookInfo,
+    path::PathBuf,
+    sync::{Arc, Mutex, MutexGuard, TryLockError},
+};
+
+use arc_swap::ArcSwap;
+use helix_event::register_hook;
+use helix_view::{
+    events::{DocumentDidChangePath, DocumentDidClose, DocumentDidOpen},
+    DocumentId,
+};
+
+use crate::{config::Config, events::PostCommand};
+
+/// Number of executed commands kept for the report.
+const COMMAND_HISTORY_LEN: usize = 20;
+
+/// Paths of the open documents. The panic hook has no access to the editor, so this is kept
+/// up to date as documents are opened, renamed and closed.
+static DOCUMENTS: Mutex<BTreeMap<DocumentId, PathBuf>> = Mutex::new(BTreeMap::new());
+
+/// The last executed commands, oldest first.
+static COMMANDS: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+
+fn report_file() -> PathBuf {
+    helix_loader::cache_dir().join("crash-report.txt")
+}
+
+/// Path of the report written by the last session that crashed.
+pub fn last_report_file() -> PathBuf {
+    helix_loader::cache_dir().join("last-crash-report.txt")
+}
+
+/// Returns the path of the report written by the previous session, if it crashed. The report is
+/// moved to [`last_report_file`] so that it is only reported once.
+pub fn take_last_report() -> Option<PathBuf> {
+    let last_report = last_report_file();
+    std::fs::rename(report_file(), &last_report).ok()?;
+    Some(last_report)
+}
+
+/// Records a command for the report, dropping the oldest one once the history is full.
+pub fn record_command(command: impl Into<String>) {
+    let mut commands = COMMANDS.lock().unwrap_or_else(|err| err.into_inner());
+    if commands.len() == COMMAND_HISTORY_LEN {
+        commands.pop_front();
+    }
+    commands.push_back(command.into());
+}
+
+pub fn install_panic_hook(config: Arc<ArcSwap<Config>>) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // there is nowhere to report a failure to: the terminal is about to be restored and the
+        // default hook prints the panic itself
+        let _ = write_report(info, &config.load());
+        default_hook(info);
+    }));
+}
+
+/// Locks `mutex` without blocking, since the panic may have happened while it was held.
+fn try_lock<T>(mutex: &Mutex<T>) -> Option<MutexGuard<'_, T>> {
+    match mutex.try_lock() {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(err)) => Some(err.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+fn write_report(info: &PanicHookInfo, config: &Config) -> std::io::Result<()> {
+    let mut report = String::new();
+    let _ = writeln!(report, "helix {}", helix_loader::VERSION_AND_GIT_HASH);
+    let _ = writeln!(report, "{info}\n");
+    let _ = writeln!(report, "{}\n", std::backtrace::Backtrace::force_capture());
+
+    let _ = writeln!(report, "Open documents:");
+    match try_lock(&DOCUMENTS) {
+        Some(documents) => {
+            for path in documents.values() {
+                let _ = writeln!(report, "  {}", path.display());
+            }
+        }
+        None => report.push_str("  (unavailable)\n"),
+    }
+
+    let _ = writeln!(report, "\nLast commands, most recent last:");
+    match try_lock(&COMMANDS) {
+        Some(commands) => {
+            for command in commands.iter() {
+                let _ = writeln!(report, "  {command}");
+            }
+        }
+        None => report.push_str("  (unavailable)\n"),
+    }
+
+    let _ = writeln!(report, "\nTheme: {:?}", config.theme);
+    let _ = writeln!(report, "Editor configuration:");
+    match toml::to_string(&config.editor) {
+        Ok(editor) => report.push_str(&editor),
+        Err(_) => {
+            let _ = writeln!(report, "{:#?}", config.editor);
+        }
+    }
+
+    std::fs::create_dir_all(helix_loader::cache_dir())?;
+    std::fs::write(report_file(), report)
+}
+
+pub fn register_hooks() {
+    register_hook!(move |event: &mut DocumentDidOpen<'_>| {
+        if let Some(path) = doc!(event.editor, &event.doc).path() {
+            DOCUMENTS.lock().unwrap().insert(event.doc, path.clone());
+        }
+        Ok(())
+    });
+    register_hook!(move |event: &mut DocumentDidChangePath<'_>| {
+        if let Some(path) = doc!(event.editor, &event.doc).path() {
+            DOCUMENTS.lock().unwrap().insert(event.doc, path.clone());
+        }
+        Ok(())
+    });
+    register_hook!(move |event: &mut DocumentDidClose<'_>| {
+        DOCUMENTS.lock().unwrap().remove(&event.doc.id());
+        Ok(())
+    });
+    register_hook!(move |event: &mut PostCommand<'_, '_>| {
+        record_command(event.command.name());
+        Ok(())
+    });
+}
@@ -1,8 +1,9 @@
 use helix_event::{events, register_event};
 use helix_view::document::Mode;
 use helix_view::events::{
-    ConfigDidChange, DiagnosticsDidChange, DocumentDidChange, DocumentDidClose, DocumentDidOpen,
-    DocumentFocusLost, LanguageServerExited, LanguageServerInitialized, SelectionDidChange,
+    ConfigDidChange, DiagnosticsDidChange, DocumentDidChange, DocumentDidChangePath,
+    DocumentDidClose, DocumentDidOpen, DocumentFocusLost, LanguageServerExited,
+    LanguageServerInitialized, SelectionDidChange,
 };
 
 use crate::commands;
@@ -20,6 +21,7 @@ pub fn register() {
     register_event::<PostCommand>();
     register_event::<DocumentDidOpen>();
     register_event::<DocumentDidChange>();
+    register_event::<DocumentDidChangePath>();
     register_event::<DocumentDidClose>();
     register_event::<DocumentFocusLost>();
     register_event::<SelectionDidChange>();
@@ -288,6 +288,11 @@ pub fn pending(&self) -> &[KeyEvent] {
         &self.state
     }
 
+    /// Clears the pending keys and returns them.
+    pub fn take_pending(&mut self) -> Vec<KeyEvent> {
+        std::mem::take(&mut self.state)
+    }
+
     pub fn sticky(&self) -> Option<&KeyTrieNode> {
         self.sticky.as_ref()
     }
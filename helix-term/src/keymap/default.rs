@@ -59,6 +59,8 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "k" => move_line_up,
             "j" => move_line_down,
             "." => goto_last_modification,
+            ";" => goto_older_change,
+            "," => goto_newer_change,
             "w" => goto_word,
         },
         ":" => command_mode,
@@ -77,6 +79,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
 
         "C" => copy_selection_on_next_line,
         "A-C" => copy_selection_on_prev_line,
+        "C-v" => select_block,
 
 
         "s" => select_regex,
@@ -110,6 +113,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
         },
         "[" => { "Left bracket"
             "d" => goto_prev_diag,
+            "b" => goto_prev_bookmark,
             "D" => goto_first_diag,
             "g" => goto_prev_change,
             "G" => goto_first_change,
@@ -125,6 +129,7 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
         },
         "]" => { "Right bracket"
             "d" => goto_next_diag,
+            "b" => goto_next_bookmark,
             "D" => goto_last_diag,
             "g" => goto_next_change,
             "G" => goto_last_change,
@@ -228,6 +233,8 @@ pub fn default() -> HashMap<Mode, KeyTrie> {
             "e" => file_explorer,
             "." => file_explorer_in_current_buffer_directory,
             "b" => buffer_picker,
+            "B" => closed_buffer_picker,
+            "A-b" => reopen_closed_buffer,
             "j" => jumplist_picker,
             "s" => lsp_or_syntax_symbol_picker,
             "S" => lsp_or_syntax_workspace_symbol_picker,
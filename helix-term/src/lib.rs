@@ -6,6 +6,7 @@
 pub mod commands;
 pub mod compositor;
 pub mod config;
+pub mod crash_report;
 pub mod events;
 pub mod health;
 pub mod job;
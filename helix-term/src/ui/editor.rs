@@ -29,7 +29,7 @@
     graphics::{Color, CursorKind, Modifier, Rect, Style},
     input::{KeyEvent, MouseButton, MouseEvent, MouseEventKind},
     keyboard::{KeyCode, KeyModifiers},
-    Document, Editor, Theme, View,
+    Document, DocumentId, Editor, Theme, View,
 };
 use std::{mem::take, num::NonZeroUsize, ops, path::PathBuf, rc::Rc};
 
@@ -40,12 +40,26 @@ pub struct EditorView {
     on_next_key: Option<(OnKeyCallback, OnKeyCallbackKind)>,
     pseudo_pending: Vec<KeyEvent>,
     pub(crate) last_insert: (commands::MappableCommand, Vec<InsertEvent>),
+    /// Keys of the last non-insert command that changed the document. When set, `.` repeats
+    /// these keys instead of the last insert session.
+    last_edit: Option<Vec<KeyEvent>>,
+    /// The non-insert key sequence that is currently being typed.
+    pending_edit: Option<PendingEdit>,
     pub(crate) completion: Option<Completion>,
     spinners: ProgressSpinners,
     /// Tracks if the terminal window is focused by reaction to terminal focus events
     terminal_focused: bool,
 }
 
+/// A key sequence typed outside of insert mode, recorded so that it can be repeated with `.`
+/// if it turns out to edit the document.
+struct PendingEdit {
+    doc: DocumentId,
+    /// The latest history revision of `doc` when the sequence started.
+    latest_revision: usize,
+    keys: Vec<KeyEvent>,
+}
+
 #[derive(Debug, Clone)]
 pub enum InsertEvent {
     Key(KeyEvent),
@@ -64,6 +78,8 @@ pub fn new(keymaps: Keymaps) -> Self {
             on_next_key: None,
             pseudo_pending: Vec::new(),
             last_insert: (commands::MappableCommand::normal_mode, Vec::new()),
+            last_edit: None,
+            pending_edit: None,
             completion: None,
             spinners: ProgressSpinners::default(),
             terminal_focused: true,
@@ -960,6 +976,7 @@ fn handle_keymap_event(
                     // we can repeat the side effect.
                     self.last_insert.0 = command.clone();
                     self.last_insert.1.clear();
+                    self.last_edit = None;
                 }
             }
 
@@ -991,21 +1008,22 @@ fn insert_mode(&mut self, cx: &mut commands::Context, event: KeyEvent) {
                         }
                     }
                 }
-                KeymapResult::Cancelled(pending) => {
-                    for ev in pending {
-                        match ev.char() {
-                            Some(ch) => commands::insert::insert_char(cx, ch),
-                            None => {
-                                if let KeymapResult::Matched(command) =
-                                    self.keymaps.get(Mode::Insert, ev)
-                                {
-                                    command.execute(cx);
-                                }
-                            }
-                        }
+                KeymapResult::Cancelled(pending) => self.insert_pending_keys(cx, pending),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    /// Inserts keys of an insert mode key sequence that did not match any mapping as text.
+    fn insert_pending_keys(&mut self, cx: &mut commands::Context, pending: Vec<KeyEvent>) {
+        for ev in pending {
+            match ev.char() {
+                Some(ch) => commands::insert::insert_char(cx, ch),
+                None => {
+                    if let KeymapResult::Matched(command) = self.keymaps.get(Mode::Insert, ev) {
+                        command.execute(cx);
                     }
                 }
-                _ => unreachable!(),
             }
         }
     }
@@ -1028,7 +1046,21 @@ fn command_mode(&mut self, mode: Mode, cxt: &mut commands::Context, event: KeyEv
             }
             // special handling for repeat operator
             (key!('.'), _) if self.keymaps.pending().is_empty() => {
+                // the repeat itself is not an edit that can be repeated
+                self.pending_edit = None;
                 for _ in 0..cxt.editor.count.map_or(1, NonZeroUsize::into) {
+                    if let Some(keys) = self.last_edit.clone() {
+                        // replay the keys of the last edit made outside of insert mode
+                        cxt.editor.count = None;
+                        for key in keys {
+                            if !self.on_next_key(OnKeyCallbackKind::PseudoPending, cxt, key) {
+                                self.command_mode(mode, cxt, key);
+                            }
+                            self.on_next_key = cxt.on_next_key_callback.take();
+                        }
+                        continue;
+                    }
+
                     // first execute whatever put us into insert mode
                     self.last_insert.0.execute(cxt);
                     let mut last_savepoint = None;
@@ -1162,6 +1194,60 @@ pub fn handle_idle_timeout(&mut self, cx: &mut commands::Context) -> EventResult
 
         EventResult::Ignored(None)
     }
+
+    /// Whether an insert mode key sequence made of plain characters (e.g. the `j` of `jk`
+    /// mapped to normal mode) is pending.
+    fn has_pending_chord(&self, editor: &Editor) -> bool {
+        let pending = self.keymaps.pending();
+        editor.mode == Mode::Insert
+            && !pending.is_empty()
+            && pending
+                .iter()
+                .all(|key| key.char().is_some() && key.modifiers.is_empty())
+    }
+
+    /// Inserts a pending insert mode key sequence that was not completed within
+    /// `chord-timeout` as text.
+    pub fn handle_chord_timeout(
+        &mut self,
+        context: &mut crate::compositor::Context,
+    ) -> EventResult {
+        if !self.has_pending_chord(context.editor) {
+            return EventResult::Ignored(None);
+        }
+
+        let mut cx = commands::Context {
+            editor: context.editor,
+            count: None,
+            register: None,
+            callback: Vec::new(),
+            on_next_key_callback: None,
+            jobs: context.jobs,
+        };
+        let pending = self.keymaps.take_pending();
+        self.insert_pending_keys(&mut cx, pending);
+
+        if let Some(on_next_key) = cx.on_next_key_callback.take() {
+            self.on_next_key = Some(on_next_key);
+        }
+        let callbacks = take(&mut cx.callback);
+
+        let config = cx.editor.config();
+        let (view, doc) = current!(cx.editor);
+        view.ensure_cursor_in_view(doc, config.scrolloff);
+
+        let callback = if callbacks.is_empty() {
+            None
+        } else {
+            let callback: crate::compositor::Callback = Box::new(move |compositor, cx| {
+                for callback in callbacks {
+                    callback(compositor, cx)
+                }
+            });
+            Some(callback)
+        };
+        EventResult::Consumed(callback)
+    }
 }
 
 /// Whether the focused doc's workspace is in restricted mode and running `trust` would
@@ -1185,6 +1271,7 @@ impl EditorView {
     fn handle_non_key_input(&mut self, cxt: &mut commands::Context) {
         cxt.editor.status_msg = None;
         cxt.editor.reset_idle_timer();
+        cxt.editor.clear_chord_timer();
         // HACKS: create a fake key event that will never trigger any actual map
         // and therefore simply acts as "dismiss"
         let null_key_event = KeyEvent {
@@ -1426,6 +1513,45 @@ fn handle_mouse_event(
             _ => EventResult::Ignored(None),
         }
     }
+    /// Records a key typed outside of insert mode as part of the current key sequence.
+    fn record_edit_key(&mut self, editor: &mut Editor, key: KeyEvent) {
+        let pending = self.pending_edit.get_or_insert_with(|| {
+            let doc = doc_mut!(editor);
+            PendingEdit {
+                doc: doc.id(),
+                latest_revision: doc.history.get_mut().latest_revision(),
+                keys: Vec::new(),
+            }
+        });
+        pending.keys.push(key);
+    }
+
+    /// Once the current key sequence is complete, remembers it as the last edit if it
+    /// committed a new revision to the document it was typed in. Undo and redo only move
+    /// between existing revisions so they are never recorded.
+    fn finish_edit_sequence(&mut self, editor: &mut Editor) {
+        let is_complete = self.keymaps.pending().is_empty()
+            && self.on_next_key.is_none()
+            && editor.count.is_none()
+            && editor.selected_register.is_none();
+        if !is_complete {
+            return;
+        }
+        let Some(pending) = self.pending_edit.take() else {
+            return;
+        };
+        // sequences entering insert mode are repeated through `last_insert`
+        if editor.mode() == Mode::Insert {
+            return;
+        }
+        let doc = doc_mut!(editor);
+        if doc.id() == pending.doc
+            && doc.history.get_mut().current_revision() > pending.latest_revision
+        {
+            self.last_edit = Some(pending.keys);
+        }
+    }
+
     fn on_next_key(
         &mut self,
         kind: OnKeyCallbackKind,
@@ -1494,6 +1620,9 @@ fn handle_event(
                 cx.editor.status_msg = None;
 
                 let mode = cx.editor.mode();
+                if mode != Mode::Insert {
+                    self.record_edit_key(cx.editor, key);
+                }
 
                 if !self.on_next_key(OnKeyCallbackKind::PseudoPending, &mut cx, key) {
                     match mode {
@@ -1557,6 +1686,13 @@ fn handle_event(
                     _ => self.pseudo_pending.clear(),
                 }
 
+                // an unfinished insert mode chord is inserted as text once `chord-timeout` elapses
+                if self.has_pending_chord(cx.editor) {
+                    cx.editor.reset_chord_timer();
+                } else {
+                    cx.editor.clear_chord_timer();
+                }
+
                 // appease borrowck
                 let callbacks = take(&mut cx.callback);
 
@@ -1577,6 +1713,8 @@ fn handle_event(
                 if mode != Mode::Insert {
                     doc.append_changes_to_history(view);
                 }
+                self.finish_edit_sequence(cx.editor);
+
                 let callback = if callbacks.is_empty() {
                     None
                 } else {
@@ -35,7 +35,7 @@
 use tui::text::{Span, Spans};
 
 use std::path::Path;
-use std::{error::Error, path::PathBuf};
+use std::{cell::RefCell, error::Error, path::PathBuf, rc::Rc};
 
 struct Utf8PathBuf {
     path: String,
@@ -103,6 +103,9 @@ pub fn raw_regex_prompt(
     let snapshot = doc.selection(view.id).clone();
     let offset_snapshot = doc.view_offset(view.id);
     let config = cx.editor.config();
+    // The error of the current input, shown above the prompt while typing
+    let error = Rc::new(RefCell::new(None::<String>));
+    let prompt_error = Rc::clone(&error);
 
     let mut prompt = Prompt::new(
         prompt,
@@ -117,6 +120,8 @@ pub fn raw_regex_prompt(
                     doc.set_view_offset(view.id, offset_snapshot);
                 }
                 PromptEvent::Update | PromptEvent::Validate => {
+                    error.borrow_mut().take();
+
                     // skip empty input
                     if input.is_empty() {
                         return;
@@ -161,26 +166,29 @@ pub fn raw_regex_prompt(
                             doc.set_selection(view.id, snapshot.clone());
                             doc.set_view_offset(view.id, offset_snapshot);
 
-                            if event == PromptEvent::Validate {
-                                let callback = async move {
-                                    let call: job::Callback = Callback::EditorCompositor(Box::new(
-                                        move |_editor: &mut Editor, compositor: &mut Compositor| {
-                                            let contents = Text::new(format!("{}", err));
-                                            let size = compositor.size();
-                                            let popup = Popup::new("invalid-regex", contents)
-                                                .position(Some(helix_core::Position::new(
-                                                    size.height as usize - 2, // 2 = statusline + commandline
-                                                    0,
-                                                )))
-                                                .auto_close(true);
-                                            compositor.replace_or_push("invalid-regex", popup);
-                                        },
-                                    ));
-                                    Ok(call)
-                                };
-
-                                cx.jobs.callback(callback);
+                            if event != PromptEvent::Validate {
+                                *error.borrow_mut() = Some(err.to_string());
+                                return;
                             }
+
+                            let callback = async move {
+                                let call: job::Callback = Callback::EditorCompositor(Box::new(
+                                    move |_editor: &mut Editor, compositor: &mut Compositor| {
+                                        let contents = Text::new(format!("{}", err));
+                                        let size = compositor.size();
+                                        let popup = Popup::new("invalid-regex", contents)
+                                            .position(Some(helix_core::Position::new(
+                                                size.height as usize - 2, // 2 = statusline + commandline
+                                                0,
+                                            )))
+                                            .auto_close(true);
+                                        compositor.replace_or_push("invalid-regex", popup);
+                                    },
+                                ));
+                                Ok(call)
+                            };
+
+                            cx.jobs.callback(callback);
                         }
                     }
                 }
@@ -188,6 +196,8 @@ pub fn raw_regex_prompt(
         },
     )
     .with_language("regex", std::sync::Arc::clone(&cx.editor.syn_loader));
+    // Unlike a popup, the prompt documentation does not take any keys away from the prompt
+    prompt.doc_fn = Box::new(move |_| prompt_error.borrow().clone().map(std::borrow::Cow::Owned));
     // Calculate initial completion
     prompt.recalculate_completion(cx.editor);
     // prompt
@@ -254,6 +254,8 @@ pub struct Picker<T: 'static + Send + Sync, D: 'static> {
 
     /// Whether to show the preview panel (default true)
     show_preview: bool,
+    /// Scroll of the preview in half pages, reset whenever the cursor moves.
+    preview_scroll: isize,
     /// Constraints for tabular formatting
     widths: Vec<Constraint>,
 
@@ -385,6 +387,7 @@ fn with(
             query,
             truncate_start: true,
             show_preview: true,
+            preview_scroll: 0,
             callback_fn: Box::new(callback_fn),
             default_action: Action::Replace,
             completion_height: 0,
@@ -472,6 +475,7 @@ pub fn move_by(&mut self, amount: u32, direction: Direction) {
                 self.cursor = self.cursor.saturating_add(len).saturating_sub(amount) % len;
             }
         }
+        self.preview_scroll = 0;
     }
 
     /// Move the cursor down by exactly one page. After the last page comes the first page.
@@ -487,6 +491,7 @@ pub fn page_down(&mut self) {
     /// Move the cursor to the first entry
     pub fn to_start(&mut self) {
         self.cursor = 0;
+        self.preview_scroll = 0;
     }
 
     /// Move the cursor to the last entry
@@ -496,6 +501,7 @@ pub fn to_end(&mut self) {
             .snapshot()
             .matched_item_count()
             .saturating_sub(1);
+        self.preview_scroll = 0;
     }
 
     pub fn selection(&self) -> Option<&T> {
@@ -524,6 +530,11 @@ pub fn toggle_preview(&mut self) {
         self.show_preview = !self.show_preview;
     }
 
+    /// Scrolls the preview of the current item by the given number of half pages.
+    pub fn scroll_preview(&mut self, amount: isize) {
+        self.preview_scroll += amount;
+    }
+
     fn prompt_handle_event(&mut self, event: &Event, cx: &mut Context) -> EventResult {
         if let EventResult::Consumed(_) = self.prompt.handle_event(event, cx) {
             self.handle_prompt_change(matches!(event, Event::Paste(_)));
@@ -540,6 +551,7 @@ fn handle_prompt_change(&mut self, is_paste: bool) {
         }
         // If the query has meaningfully changed, reset the cursor to the top of the results.
         self.cursor = 0;
+        self.preview_scroll = 0;
         // Have nucleo reparse each changed column.
         for (i, column) in self
             .columns
@@ -684,9 +696,13 @@ fn render_picker(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context)
         let status = self.matcher.tick(10);
         let snapshot = self.matcher.snapshot();
         if status.changed {
-            self.cursor = self
+            let cursor = self
                 .cursor
-                .min(snapshot.matched_item_count().saturating_sub(1))
+                .min(snapshot.matched_item_count().saturating_sub(1));
+            if cursor != self.cursor {
+                self.cursor = cursor;
+                self.preview_scroll = 0;
+            }
         }
 
         let text_style = cx.editor.theme.get("ui.text");
@@ -896,6 +912,9 @@ fn render_preview(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context
         let inner = inner.inner(margin);
         BLOCK.render(area, surface);
 
+        let preview_scroll = self.preview_scroll;
+        let mut clamped_scroll = None;
+
         if let Some((preview, range)) = self.get_preview(cx.editor) {
             let doc = match preview.document() {
                 Some(doc)
@@ -957,6 +976,23 @@ fn render_preview(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context
                 }
             }
 
+            if preview_scroll != 0 {
+                let text = doc.text().slice(..);
+                let half_page = (inner.height as isize / 2).max(1);
+                let first_line = text.char_to_line(offset.anchor) as isize;
+                let last_line = text.len_lines().saturating_sub(1) as isize;
+                // Don't keep scrolling past either end of the document, so that scrolling back
+                // takes effect immediately.
+                let scroll = preview_scroll.clamp(
+                    -(first_line + half_page - 1) / half_page,
+                    (last_line - first_line + half_page - 1) / half_page,
+                );
+                clamped_scroll = Some(scroll);
+                let line = (first_line + scroll * half_page).clamp(0, last_line) as usize;
+                offset.anchor = text.line_to_char(line);
+                offset.vertical_offset = 0;
+            }
+
             let loader = cx.editor.syn_loader.load();
             let config = cx.editor.config();
 
@@ -1020,6 +1056,10 @@ fn render_preview(&mut self, area: Rect, surface: &mut Surface, cx: &mut Context
                 decorations,
             );
         }
+
+        if let Some(scroll) = clamped_scroll {
+            self.preview_scroll = scroll;
+        }
     }
 }
 
@@ -1161,6 +1201,12 @@ fn handle_event(&mut self, event: &Event, ctx: &mut Context) -> EventResult {
             ctrl!('t') => {
                 self.toggle_preview();
             }
+            alt!(Down) => {
+                self.scroll_preview(1);
+            }
+            alt!(Up) => {
+                self.scroll_preview(-1);
+            }
             _ => {
                 self.prompt_handle_event(event, ctx);
             }
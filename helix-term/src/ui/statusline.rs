@@ -139,6 +139,7 @@ fn get_render_function<'a, F>(element_id: StatusLineElementID) -> impl Fn(&mut R
             render_file_modification_indicator
         }
         helix_view::editor::StatusLineElement::ReadOnlyIndicator => render_read_only_indicator,
+        helix_view::editor::StatusLineElement::ViewPinnedIndicator => render_view_pinned_indicator,
         helix_view::editor::StatusLineElement::FileEncoding => render_file_encoding,
         helix_view::editor::StatusLineElement::FileLineEnding => render_file_line_ending,
         helix_view::editor::StatusLineElement::FileIndentStyle => render_file_indent_style,
@@ -500,6 +501,18 @@ fn render_read_only_indicator<'a, F>(context: &mut RenderContext<'a>, write: F)
     write(context, title.into());
 }
 
+fn render_view_pinned_indicator<'a, F>(context: &mut RenderContext<'a>, write: F)
+where
+    F: Fn(&mut RenderContext<'a>, Span<'a>) + Copy,
+{
+    let title = if context.view.follow_cursor {
+        ""
+    } else {
+        " [pinned] "
+    };
+    write(context, title.into());
+}
+
 fn render_file_base_name<'a, F>(context: &mut RenderContext<'a>, write: F)
 where
     F: Fn(&mut RenderContext<'a>, Span<'a>) + Copy,
@@ -158,3 +158,16 @@ async fn percent_escaping() -> anyhow::Result<()> {
     .await?;
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn unknown_command_suggestion() -> anyhow::Result<()> {
+    test_statusline(
+        ":wrte",
+        "no such command: 'wrte', did you mean 'write'?",
+        Severity::Error,
+    )
+    .await?;
+    test_statusline(":zzzzzz", "no such command: 'zzzzzz'", Severity::Error).await?;
+
+    Ok(())
+}
@@ -120,6 +120,69 @@ async fn test_selection_duplication() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_select_block() -> anyhow::Result<()> {
+    test((
+        indoc! {"\
+            #[lorem
+            ip|]#sum
+            dolor
+            "},
+        "<C-v>",
+        indoc! {"\
+            #(lo|)#rem
+            #[ip|]#sum
+            dolor
+            "},
+    ))
+    .await?;
+
+    // Lines shorter than the block are clipped
+    test((
+        indoc! {"\
+            #[lorem
+            x
+            dol|]#or
+            "},
+        "<C-v>",
+        indoc! {"\
+            #(lor|)#em
+            #(x|)#
+            #[dol|]#or
+            "},
+    ))
+    .await?;
+
+    // A point at the start of a line is a single column block on that line
+    test((
+        indoc! {"\
+            lorem
+            #[|]#ipsum
+            "},
+        "<C-v>",
+        indoc! {"\
+            lorem
+            #[i|]#psum
+            "},
+    ))
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_repeat_normal_mode_edit() -> anyhow::Result<()> {
+    test(("#[a|]#bc\n", "rxl.", "x#[x|]#c\n")).await?;
+
+    // undo is not an edit that gets repeated
+    test(("#[a|]#bc\n", "rxu.", "#[x|]#bc\n")).await?;
+
+    // an insert session replaces the previously recorded edit
+    test(("#[a|]#bc\n", "rxiy<esc>.", "yy#[|x]#bc\n")).await?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_goto_file_impl() -> anyhow::Result<()> {
     let file = tempfile::NamedTempFile::new()?;
@@ -928,6 +991,92 @@ async fn global_search_with_multibyte_chars() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn search_with_invalid_regex() -> anyhow::Result<()> {
+    // The error shown while typing an invalid pattern must not take keys away from the
+    // prompt: escape closes the prompt and the following keys reach normal mode.
+    test(("#[a|]#bc\n", "/foo(<esc>x", "#[abc\n|]#")).await?;
+    // ctrl-u still edits the prompt.
+    test(("#[a|]#bc\n", "/foo(<C-u>bc<ret>", "a#[bc|]#\n")).await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn buffer_lines_picker() -> anyhow::Result<()> {
+    use helix_core::hashmap;
+    use helix_term::keymap;
+    use helix_view::document::Mode;
+
+    let mut config = Config::default();
+    config.keys.insert(
+        Mode::Normal,
+        keymap!({"Normal Mode"
+            "C-l" => buffer_lines_picker,
+        }),
+    );
+    let mut app = AppBuilder::new()
+        .with_config(config)
+        .with_input_text("#[a|]#lpha\nbeta\ngamma\n")
+        .build()?;
+
+    // The lines are streamed into the picker, so let it settle before accepting a match.
+    test_key_sequences(
+        &mut app,
+        vec![
+            (Some("<C-l>gamma"), None),
+            (
+                Some("<ret>"),
+                Some(&|app| {
+                    let (view, doc) = helix_view::current_ref!(app.editor);
+                    let text = doc.text().slice(..);
+                    assert_eq!(2, doc.selection(view.id).primary().cursor_line(text));
+                }),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn goto_link_hint_skips_comment_slashes() -> anyhow::Result<()> {
+    use helix_core::hashmap;
+    use helix_term::keymap;
+    use helix_view::document::Mode;
+
+    let mut config = Config::default();
+    config.keys.insert(
+        Mode::Normal,
+        keymap!({"Normal Mode"
+            "C-l" => goto_link_hint,
+        }),
+    );
+    let file = helpers::temp_file_with_contents("target\n")?;
+    let mut app = AppBuilder::new()
+        .with_config(config)
+        .with_input_text(format!("#[/|]#/ see {}\n", file.path().display()))
+        .build()?;
+
+    // The first label goes to the path, not to the slashes of the comment.
+    test_key_sequences(
+        &mut app,
+        vec![(
+            Some("<C-l>aa"),
+            Some(&|app| {
+                let doc = helix_view::doc!(app.editor);
+                assert_eq!(Some(&helix_stdx::path::normalize(file.path())), doc.path());
+            }),
+        )],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn align_selections_with_varying_columns() -> anyhow::Result<()> {
     test((
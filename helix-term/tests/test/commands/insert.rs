@@ -639,3 +639,66 @@ async fn test_indent_with_spaces() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_insert_chord_timeout() -> anyhow::Result<()> {
+    use helix_core::hashmap;
+    use helix_term::keymap;
+    use helix_view::document::Mode;
+
+    let mut config = Config::default();
+    config.keys.insert(
+        Mode::Insert,
+        keymap!({"Insert Mode"
+            "j" => { "Chord"
+                "k" => normal_mode,
+            },
+        }),
+    );
+    config.editor.chord_timeout = std::time::Duration::from_millis(50);
+
+    // A completed chord runs its command.
+    let mut app = AppBuilder::new()
+        .with_config(config.clone())
+        .with_input_text("#[a|]#bc\n")
+        .build()?;
+    test_key_sequence(
+        &mut app,
+        Some("ijk"),
+        Some(&|app| {
+            assert_eq!(Mode::Normal, app.editor.mode());
+            assert_eq!("abc\n", helix_view::doc!(app.editor).text().to_string());
+        }),
+        false,
+    )
+    .await?;
+
+    // A timed-out `j` is inserted and the next `k` no longer completes the chord.
+    let mut app = AppBuilder::new()
+        .with_config(config)
+        .with_input_text("#[a|]#bc\n")
+        .build()?;
+    test_key_sequences(
+        &mut app,
+        vec![
+            (
+                Some("ij"),
+                Some(&|app| {
+                    assert_eq!(Mode::Insert, app.editor.mode());
+                    assert_eq!("jabc\n", helix_view::doc!(app.editor).text().to_string());
+                }),
+            ),
+            (
+                Some("k"),
+                Some(&|app| {
+                    assert_eq!(Mode::Insert, app.editor.mode());
+                    assert_eq!("jkabc\n", helix_view::doc!(app.editor).text().to_string());
+                }),
+            ),
+        ],
+        false,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -842,3 +842,93 @@ async fn tree_sitter_motions_work_across_injections() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn changelist_navigation() -> anyhow::Result<()> {
+    // Changes are made to the lines c, a, b and then a again. Revisiting the first line
+    // must not keep `g;` from reaching the oldest change.
+    let edits = "jjA!<esc>ggA!<esc>jA!<esc>kA!<esc>";
+    test((
+        "#[a|]#\nb\nc\n",
+        format!("{edits}g;g;g;g;"),
+        "a!!\nb!\nc!#[|]#\n",
+    ))
+    .await?;
+    test((
+        "#[a|]#\nb\nc\n",
+        format!("{edits}g;g;g;g;g,g,"),
+        "a!!\nb!#[|]#\nc!\n",
+    ))
+    .await?;
+    test((
+        "#[a|]#\nb\nc\n",
+        format!("{edits}g;g,"),
+        "a!!#[|]#\nb!\nc!\n",
+    ))
+    .await?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn bookmark_navigation() -> anyhow::Result<()> {
+    use helix_core::hashmap;
+    use helix_term::keymap;
+    use helix_view::document::Mode;
+
+    let mut config = Config::default();
+    config.keys.insert(
+        Mode::Normal,
+        keymap!({"Normal Mode"
+            "C-b" => toggle_bookmark,
+        }),
+    );
+    let app = || AppBuilder::new().with_config(config.clone());
+    let bookmarks = "j<C-b>jj<C-b>";
+
+    test_with_config(
+        app(),
+        (
+            "#[a|]#\nb\nc\nd\n",
+            format!("{bookmarks}gg]b"),
+            "a\n#[|]#b\nc\nd\n",
+        ),
+    )
+    .await?;
+    test_with_config(
+        app(),
+        (
+            "#[a|]#\nb\nc\nd\n",
+            format!("{bookmarks}gg]b]b]b"),
+            "a\nb\nc\n#[|]#d\n",
+        ),
+    )
+    .await?;
+    test_with_config(
+        app(),
+        (
+            "#[a|]#\nb\nc\nd\n",
+            format!("{bookmarks}[b[b"),
+            "a\n#[|]#b\nc\nd\n",
+        ),
+    )
+    .await?;
+    // toggling twice removes the bookmark
+    test_with_config(
+        app(),
+        (
+            "#[a|]#\nb\nc\nd\n",
+            format!("{bookmarks}<C-b>gg]b]b"),
+            "a\n#[|]#b\nc\nd\n",
+        ),
+    )
+    .await?;
+    // deleting a bookmarked line moves its bookmark to the following line
+    test_with_config(
+        app(),
+        ("#[a|]#\nb\nc\nd\n", "j<C-b>xdgg]b", "a\n#[|]#c\nd\n"),
+    )
+    .await?;
+
+    Ok(())
+}
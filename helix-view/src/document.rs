@@ -155,6 +155,8 @@ pub struct Document {
     pub(crate) document_highlights: HashMap<ViewId, DocumentHighlights>,
     /// LSP code action hints for each view.
     pub(crate) code_action_hints: HashSet<ViewId>,
+    /// Bookmarked lines, stored as the char index of the line start and kept sorted.
+    pub(crate) bookmarks: Vec<usize>,
     /// Set to `true` when the document is updated, reset to `false` on the next inlay hints
     /// update from the LSP
     pub inlay_hints_oudated: bool,
@@ -767,6 +769,7 @@ pub fn from(
             jump_labels: HashMap::new(),
             document_highlights: HashMap::new(),
             code_action_hints: HashSet::new(),
+            bookmarks: Vec::new(),
             color_swatches: None,
             document_links: Vec::new(),
             color_swatch_controller: TaskController::new(),
@@ -1215,7 +1218,8 @@ pub fn detect_language_config(
     ) -> Option<Arc<syntax::config::LanguageConfiguration>> {
         let language = loader
             .language_for_filename(self.path.as_ref()?)
-            .or_else(|| loader.language_for_shebang(self.text().slice(..)))?;
+            .or_else(|| loader.language_for_shebang(self.text().slice(..)))
+            .or_else(|| loader.language_for_modeline(self.text().slice(..)))?;
 
         Some(loader.language(language).config().clone())
     }
@@ -1570,6 +1574,13 @@ fn apply_impl(
             )
         });
 
+        // map bookmarks over changes too, keeping them at the start of their line
+        changes.update_positions(self.bookmarks.iter_mut().map(|pos| (pos, Assoc::After)));
+        for pos in &mut self.bookmarks {
+            *pos = self.text.line_to_char(self.text.char_to_line(*pos));
+        }
+        self.bookmarks.dedup();
+
         // Update the inlay hint annotations' positions, helping ensure they are displayed in the proper place
         let apply_inlay_hint_changes = |annotations: &mut Vec<InlineAnnotation>| {
             changes.update_positions(
@@ -2477,6 +2488,28 @@ pub fn code_action_controller(&mut self, view_id: ViewId) -> &mut TaskController
         self.code_action_controllers.entry(view_id).or_default()
     }
 
+    /// Toggles the bookmark on the given line. Returns `true` if the line is now bookmarked.
+    pub fn toggle_bookmark(&mut self, line: usize) -> bool {
+        let pos = self.text.line_to_char(line);
+        match self.bookmarks.binary_search(&pos) {
+            Ok(idx) => {
+                self.bookmarks.remove(idx);
+                false
+            }
+            Err(idx) => {
+                self.bookmarks.insert(idx, pos);
+                true
+            }
+        }
+    }
+
+    /// Bookmarked lines in ascending order.
+    pub fn bookmarked_lines(&self) -> impl Iterator<Item = usize> + '_ {
+        self.bookmarks
+            .iter()
+            .map(|&pos| self.text.char_to_line(pos))
+    }
+
     /// Get the inlay hints for this document and `view_id`.
     pub fn inlay_hints(&self, view_id: ViewId) -> Option<&DocumentInlayHints> {
         self.inlay_hints.get(&view_id)
@@ -2574,6 +2607,55 @@ fn changeset_to_changes_ignore_line_endings() {
         );
     }
 
+    #[test]
+    fn bookmarks_follow_changes() {
+        let mut doc = Document::from(
+            Rope::from("a\nb\nc\nd\n"),
+            None,
+            Arc::new(ArcSwap::new(Arc::new(Config::default()))),
+            Arc::new(ArcSwap::from_pointee(syntax::Loader::default())),
+        );
+        let view = ViewId::default();
+        doc.set_selection(view, Selection::single(0, 0));
+        let bookmarks = |doc: &Document| doc.bookmarked_lines().collect::<Vec<_>>();
+
+        assert!(doc.toggle_bookmark(1));
+        assert!(doc.toggle_bookmark(3));
+        assert!(doc.toggle_bookmark(2));
+        assert!(!doc.toggle_bookmark(3));
+        assert_eq!(bookmarks(&doc), [1, 2]);
+
+        // inserting a line above shifts the bookmarks down
+        let transaction =
+            Transaction::change(doc.text(), vec![(0, 0, Some("x\n".into()))].into_iter());
+        doc.apply(&transaction, view);
+        assert_eq!(doc.text(), "x\na\nb\nc\nd\n");
+        assert_eq!(bookmarks(&doc), [2, 3]);
+
+        // deleting a bookmarked line moves its bookmark to the next line, where it merges
+        // with the bookmark already on that line
+        let transaction = Transaction::change(doc.text(), vec![(4, 6, None)].into_iter());
+        doc.apply(&transaction, view);
+        assert_eq!(doc.text(), "x\na\nc\nd\n");
+        assert_eq!(bookmarks(&doc), [2]);
+
+        // joining a bookmarked line onto the previous one moves the bookmark to the start of
+        // the joined line, and bookmarks on both lines are merged
+        assert!(doc.toggle_bookmark(1));
+        let transaction =
+            Transaction::change(doc.text(), vec![(3, 4, Some(" ".into()))].into_iter());
+        doc.apply(&transaction, view);
+        assert_eq!(doc.text(), "x\na c\nd\n");
+        assert_eq!(bookmarks(&doc), [1]);
+
+        // editing inside a line keeps the bookmark at the line start
+        let transaction =
+            Transaction::change(doc.text(), vec![(2, 2, Some("> ".into()))].into_iter());
+        doc.apply(&transaction, view);
+        assert_eq!(bookmarks(&doc), [1]);
+        assert_eq!(doc.bookmarks, [2]);
+    }
+
     #[test]
     fn changeset_to_changes() {
         use helix_lsp::{lsp, Client, OffsetEncoding};
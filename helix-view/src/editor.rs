@@ -4,7 +4,7 @@
     document::{
         DocumentOpenError, DocumentSavedEventFuture, DocumentSavedEventResult, Mode, SavePoint,
     },
-    events::{DocumentDidClose, DocumentDidOpen, DocumentFocusLost},
+    events::{DocumentDidChangePath, DocumentDidClose, DocumentDidOpen, DocumentFocusLost},
     graphics::{CursorKind, Rect},
     handlers::Handlers,
     info::Info,
@@ -64,6 +64,10 @@
 };
 
 pub const DIR_STACK_CAP: usize = 10;
+/// Maximum number of statusline messages kept for `:messages`.
+pub const STATUS_HISTORY_CAP: usize = 200;
+/// Maximum number of closed documents remembered for reopening.
+pub const CLOSED_DOCUMENTS_CAP: usize = 32;
 pub const DEFAULT_AUTO_SAVE_DELAY: u64 = 3000;
 
 fn deserialize_duration_millis<'de, D>(deserializer: D) -> Result<Duration, D::Error>
@@ -353,6 +357,13 @@ pub struct Config {
         deserialize_with = "deserialize_duration_millis"
     )]
     pub completion_timeout: Duration,
+    /// Time in milliseconds after which an unfinished insert mode key sequence made of plain
+    /// characters, like the `j` of a `jk` mapping, is inserted as text. Defaults to 1000ms.
+    #[serde(
+        serialize_with = "serialize_duration_millis",
+        deserialize_with = "deserialize_duration_millis"
+    )]
+    pub chord_timeout: Duration,
     /// Whether to insert the completion suggestion on hover. Defaults to true.
     pub preview_completion_insert: bool,
     pub completion_trigger_len: u8,
@@ -643,6 +654,9 @@ pub struct LspConfig {
     pub snippets: bool,
     /// Whether to include declaration in the goto reference query
     pub goto_reference_include_declaration: bool,
+    /// Use the selection ranges of the language server instead of the syntax tree to expand
+    /// and shrink selections
+    pub selection_ranges: bool,
 }
 
 impl Default for LspConfig {
@@ -659,6 +673,7 @@ fn default() -> Self {
             snippets: true,
             goto_reference_include_declaration: true,
             display_color_swatches: true,
+            selection_ranges: false,
         }
     }
 }
@@ -695,6 +710,7 @@ fn default() -> Self {
                 E::FileName,
                 E::ReadOnlyIndicator,
                 E::FileModificationIndicator,
+                E::ViewPinnedIndicator,
             ],
             center: vec![],
             right: vec![
@@ -754,6 +770,9 @@ pub enum StatusLineElement {
     /// An indicator that shows `"[readonly]"` when a file cannot be written
     ReadOnlyIndicator,
 
+    /// An indicator that shows `"[pinned]"` when the view doesn't follow the cursor
+    ViewPinnedIndicator,
+
     /// The file encoding
     FileEncoding,
 
@@ -909,6 +928,8 @@ pub enum GutterType {
     Diff,
     /// Indicator for when code actions are available
     CodeActionHint,
+    /// Indicator for bookmarked lines
+    Bookmarks,
 }
 
 impl std::str::FromStr for GutterType {
@@ -921,8 +942,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "line-numbers" => Ok(Self::LineNumbers),
             "diff" => Ok(Self::Diff),
             "code-action-hint" => Ok(Self::CodeActionHint),
+            "bookmarks" => Ok(Self::Bookmarks),
             _ => anyhow::bail!(
-                "Gutter type can only be `diagnostics`, `spacer`, `line-numbers` or `diff`."
+                "Gutter type can only be `diagnostics`, `spacer`, `line-numbers`, `diff`, `code-action-hint` or `bookmarks`."
             ),
         }
     }
@@ -1198,6 +1220,7 @@ fn default() -> Self {
             auto_save: AutoSave::default(),
             idle_timeout: Duration::from_millis(250),
             completion_timeout: Duration::from_millis(250),
+            chord_timeout: Duration::from_millis(1000),
             preview_completion_insert: true,
             completion_trigger_len: 2,
             auto_info: true,
@@ -1310,17 +1333,22 @@ pub struct Editor {
     pub last_selection: Option<Selection>,
 
     pub status_msg: Option<(Cow<'static, str>, Severity)>,
+    /// Messages shown in the statusline during this session, oldest first.
+    pub status_history: VecDeque<(Cow<'static, str>, Severity)>,
     pub autoinfo: Option<Info>,
 
     pub config: Arc<dyn DynAccess<Config>>,
     pub auto_pairs: Option<AutoPairs>,
 
     pub idle_timer: Pin<Box<Sleep>>,
+    chord_timer: Pin<Box<Sleep>>,
     redraw_timer: Pin<Box<Sleep>>,
     last_motion: Option<Motion>,
     pub last_completion: Option<CompleteAction>,
     pub last_cwd: Option<PathBuf>,
     pub dir_stack: VecDeque<PathBuf>,
+    /// Paths and selections of recently closed documents, most recent last.
+    pub closed_documents: VecDeque<(PathBuf, Selection)>,
 
     pub exit_code: i32,
 
@@ -1354,6 +1382,7 @@ pub enum EditorEvent {
     LanguageServerMessage((LanguageServerId, Call)),
     DebuggerEvent((DebugAdapterId, dap::Payload)),
     IdleTimer,
+    ChordTimer,
     Redraw,
 }
 
@@ -1452,8 +1481,10 @@ pub fn new(
                 |config: &Config| &config.clipboard_provider,
             ))),
             status_msg: None,
+            status_history: VecDeque::new(),
             autoinfo: None,
             idle_timer: Box::pin(sleep(conf.idle_timeout)),
+            chord_timer: Box::pin(sleep(Duration::MAX)),
             redraw_timer: Box::pin(sleep(Duration::MAX)),
             last_motion: None,
             last_completion: None,
@@ -1467,6 +1498,7 @@ pub fn new(
             mouse_down_range: None,
             cursor_cache: CursorCache::default(),
             dir_stack: VecDeque::with_capacity(DIR_STACK_CAP),
+            closed_documents: VecDeque::new(),
             workspace_trust,
         }
     }
@@ -1531,29 +1563,51 @@ pub fn reset_idle_timer(&mut self) {
             .reset(Instant::now() + config.idle_timeout);
     }
 
+    pub fn clear_chord_timer(&mut self) {
+        self.chord_timer
+            .as_mut()
+            .reset(Instant::now() + Duration::from_secs(86400 * 365 * 30));
+    }
+
+    pub fn reset_chord_timer(&mut self) {
+        let config = self.config();
+        self.chord_timer
+            .as_mut()
+            .reset(Instant::now() + config.chord_timeout);
+    }
+
     pub fn clear_status(&mut self) {
         self.status_msg = None;
     }
 
+    /// Shows a message in the statusline and records it in the message history.
+    pub fn set_status_msg(&mut self, msg: Cow<'static, str>, severity: Severity) {
+        if self.status_history.len() == STATUS_HISTORY_CAP {
+            self.status_history.pop_front();
+        }
+        self.status_history.push_back((msg.clone(), severity));
+        self.status_msg = Some((msg, severity));
+    }
+
     #[inline]
     pub fn set_status<T: Into<Cow<'static, str>>>(&mut self, status: T) {
         let status = status.into();
         log::debug!("editor status: {}", status);
-        self.status_msg = Some((status, Severity::Info));
+        self.set_status_msg(status, Severity::Info);
     }
 
     #[inline]
     pub fn set_error<T: Into<Cow<'static, str>>>(&mut self, error: T) {
         let error = error.into();
         log::debug!("editor error: {}", error);
-        self.status_msg = Some((error, Severity::Error));
+        self.set_status_msg(error, Severity::Error);
     }
 
     #[inline]
     pub fn set_warning<T: Into<Cow<'static, str>>>(&mut self, warning: T) {
         let warning = warning.into();
         log::warn!("editor warning: {}", warning);
-        self.status_msg = Some((warning, Severity::Warning));
+        self.set_status_msg(warning, Severity::Warning);
     }
 
     #[inline]
@@ -1777,6 +1831,12 @@ pub fn delete_path(&mut self, path: &Path, recursive: bool) -> io::Result<()> {
     pub fn set_doc_path(&mut self, doc_id: DocumentId, path: &Path) {
         let doc = doc_mut!(self, &doc_id);
         let old_path = doc.path();
+        // keep the language picked for a scratch buffer unless the new path determines one
+        let scratch_language = if old_path.is_none() {
+            doc.language.clone()
+        } else {
+            None
+        };
 
         if let Some(old_path) = old_path {
             // sanity check, should not occur but some callers (like an LSP) may
@@ -1796,7 +1856,19 @@ pub fn set_doc_path(&mut self, doc_id: DocumentId, path: &Path) {
         doc.language_servers.clear();
         doc.set_path(Some(path));
         doc.detect_editor_config();
-        self.refresh_doc_language(doc_id)
+        self.refresh_doc_language(doc_id);
+
+        let doc = doc_mut!(self, &doc_id);
+        if doc.language.is_none() && scratch_language.is_some() {
+            let loader = self.syn_loader.load();
+            doc.set_language(scratch_language, &loader);
+            self.refresh_language_servers(doc_id);
+        }
+
+        helix_event::dispatch(DocumentDidChangePath {
+            editor: self,
+            doc: doc_id,
+        });
     }
 
     pub fn refresh_doc_language(&mut self, doc_id: DocumentId) {
@@ -2155,6 +2227,21 @@ pub fn close(&mut self, id: ViewId) {
     }
 
     pub fn close_document(&mut self, doc_id: DocumentId, force: bool) -> Result<(), CloseError> {
+        self.close_document_impl(doc_id, force, true)
+    }
+
+    /// Closes a document that was only opened to be edited in the background, without
+    /// remembering it in `closed_documents`.
+    pub fn discard_document(&mut self, doc_id: DocumentId, force: bool) -> Result<(), CloseError> {
+        self.close_document_impl(doc_id, force, false)
+    }
+
+    fn close_document_impl(
+        &mut self,
+        doc_id: DocumentId,
+        force: bool,
+        remember: bool,
+    ) -> Result<(), CloseError> {
         let doc = match self.documents.get(&doc_id) {
             Some(doc) => doc,
             None => return Err(CloseError::DoesNotExist),
@@ -2163,6 +2250,23 @@ pub fn close_document(&mut self, doc_id: DocumentId, force: bool) -> Result<(),
             return Err(CloseError::BufferModified(doc.display_name().into_owned()));
         }
 
+        if let Some(path) = doc.path().filter(|_| remember) {
+            let view_id = if doc.selections().contains_key(&self.tree.focus) {
+                Some(self.tree.focus)
+            } else {
+                doc.selections().keys().next().copied()
+            };
+            let selection = view_id
+                .map(|view_id| doc.selection(view_id).clone())
+                .unwrap_or_else(|| Selection::point(0));
+            let path = path.clone();
+            self.closed_documents.retain(|(closed, _)| *closed != path);
+            if self.closed_documents.len() == CLOSED_DOCUMENTS_CAP {
+                self.closed_documents.pop_front();
+            }
+            self.closed_documents.push_back((path, selection));
+        }
+
         // This will also disallow any follow-up writes
         self.saves.remove(&doc_id);
 
@@ -2504,6 +2608,9 @@ pub async fn wait_event(&mut self) -> EditorEvent {
                 _ = &mut self.idle_timer  => {
                     return EditorEvent::IdleTimer
                 }
+                _ = &mut self.chord_timer  => {
+                    return EditorEvent::ChordTimer
+                }
             }
         }
     }
@@ -16,6 +16,8 @@
         changes: &'a ChangeSet,
         ghost_transaction: bool
     }
+    // called after the path of a document changed, e.g. on save-as or a move
+    DocumentDidChangePath<'a> { editor: &'a mut Editor, doc: DocumentId }
     DocumentDidClose<'a> {
         editor: &'a mut Editor,
         doc: Document
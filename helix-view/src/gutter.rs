@@ -33,6 +33,7 @@ pub fn style<'doc>(
             GutterType::Spacer => padding(editor, doc, view, theme, is_focused),
             GutterType::Diff => diff(editor, doc, view, theme, is_focused),
             GutterType::CodeActionHint => code_action_hint(editor, doc, view, theme, is_focused),
+            GutterType::Bookmarks => bookmarks(editor, doc, view, theme, is_focused),
         }
     }
 
@@ -43,6 +44,7 @@ pub fn width(self, view: &View, doc: &Document) -> usize {
             GutterType::Spacer => 1,
             GutterType::Diff => 1,
             GutterType::CodeActionHint => 1,
+            GutterType::Bookmarks => 1,
         }
     }
 }
@@ -351,6 +353,28 @@ pub fn code_action_hint<'doc>(
     )
 }
 
+pub fn bookmarks<'doc>(
+    _editor: &'doc Editor,
+    doc: &'doc Document,
+    _view: &View,
+    theme: &Theme,
+    _is_focused: bool,
+) -> GutterFn<'doc> {
+    let style = theme
+        .try_get("ui.gutter.bookmark")
+        .unwrap_or_else(|| theme.get("info"));
+    let lines: Vec<usize> = doc.bookmarked_lines().collect();
+
+    Box::new(
+        move |line: usize, _selected: bool, first_visual_line: bool, out: &mut String| {
+            (first_visual_line && lines.binary_search(&line).is_ok()).then(|| {
+                write!(out, "◆").unwrap();
+                style
+            })
+        },
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -159,6 +159,13 @@ pub struct View {
     pub object_selections: Vec<Selection>,
     /// all gutter-related configuration settings, used primarily for gutter rendering
     pub gutters: GutterConfig,
+    /// whether the view scrolls to keep the cursor visible. When disabled the view stays
+    /// pinned in place while the cursor moves or edits happen outside of it.
+    pub follow_cursor: bool,
+    /// The document, history revision and index into its changelist (see
+    /// `History::edit_positions`) of the last change navigated to. Navigation starts over
+    /// from the most recent change once the document or its revision changes.
+    pub changelist_index: Option<(DocumentId, usize, usize)>,
     /// A mapping between documents and the last history revision the view was updated at.
     /// Changes between documents and views are synced lazily when switching windows. This
     /// mapping keeps track of the last applied history revision so that only new changes
@@ -195,6 +202,8 @@ pub fn new(doc: DocumentId, gutters: GutterConfig) -> Self {
             last_modified_docs: [None, None],
             object_selections: Vec::new(),
             gutters,
+            follow_cursor: true,
+            changelist_index: None,
             doc_revisions: HashMap::new(),
             diagnostics_handler: DiagnosticsHandler::new(),
         }
@@ -351,11 +360,16 @@ pub fn offset_coords_to_in_view_center<const CENTERING: bool>(
     }
 
     pub fn ensure_cursor_in_view(&self, doc: &mut Document, scrolloff: usize) {
+        if !self.follow_cursor {
+            return;
+        }
         if let Some(offset) = self.offset_coords_to_in_view_center::<false>(doc, scrolloff) {
             doc.set_view_offset(self.id, offset);
         }
     }
 
+    /// Scrolls the cursor into the center of the view. This is used for explicit jumps and
+    /// therefore also applies to views that don't follow the cursor.
     pub fn ensure_cursor_in_view_center(&self, doc: &mut Document, scrolloff: usize) {
         if let Some(offset) = self.offset_coords_to_in_view_center::<true>(doc, scrolloff) {
             doc.set_view_offset(self.id, offset);